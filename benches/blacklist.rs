@@ -0,0 +1,41 @@
+//! Benchmark of blacklist parsing and lookups.
+//!
+//! Run with `cargo bench --bench blacklist`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pwd_strength::{extend_blacklist_from_path, init_blacklist_from_path, is_blacklisted};
+use std::hint::black_box;
+use std::io::Write;
+
+const ENTRIES: usize = 1_000_000;
+
+fn blacklist(c: &mut Criterion) {
+    let path = std::env::temp_dir().join(format!("pwd-strength-bench-{}.txt", std::process::id()));
+    {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(&path).expect("create list"));
+        for i in 0..ENTRIES {
+            writeln!(file, "password{}", i).expect("write list");
+        }
+    }
+    init_blacklist_from_path(&path).expect("blacklist should load");
+
+    // The list is loaded once per process; merging the same file again
+    // parses it in full, so it tracks the initialization cost.
+    let mut group = c.benchmark_group("blacklist");
+    group.sample_size(10);
+    group.bench_function("extend from file", |b| {
+        b.iter(|| extend_blacklist_from_path(&path).expect("blacklist should merge"))
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("is_blacklisted");
+    for (name, input) in [("hit", "password99999"), ("miss", "Kx7#mQ2!vR9$")] {
+        group.bench_function(name, |b| b.iter(|| is_blacklisted(black_box(input))));
+    }
+    group.finish();
+
+    let _ = std::fs::remove_file(&path);
+}
+
+criterion_group!(benches, blacklist);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+//! Benchmark of the single-pass character statistics.
+//!
+//! Run with `cargo bench --bench char_stats`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pwd_strength::CharStats;
+use std::hint::black_box;
+
+fn char_stats(c: &mut Criterion) {
+    let mut group = c.benchmark_group("char_stats");
+    for (name, input) in [
+        ("ascii short", "Kx7#mQ2!"),
+        ("ascii long", "Correct-Horse-Battery-Staple-42!"),
+        (
+            "non-ascii",
+            "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}\u{00e9}2024!",
+        ),
+    ] {
+        group.bench_function(name, |b| b.iter(|| CharStats::compute(black_box(input))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, char_stats);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Benchmark of full evaluations, `strength_only` and `quick_reject` with a
+//! loaded blacklist.
+//!
+//! Run with `cargo bench --bench evaluator`.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use pwd_strength::{
+    EvaluatorConfig, evaluate_password_strength, init_blacklist_from_iter, quick_reject,
+    strength_only,
+};
+use secrecy::SecretString;
+use std::hint::black_box;
+
+fn secret(input: &str) -> SecretString {
+    SecretString::new(input.to_string().into())
+}
+
+fn evaluator(c: &mut Criterion) {
+    init_blacklist_from_iter((0..100_000).map(|i| format!("password{}", i)))
+        .expect("blacklist should load");
+    let config = EvaluatorConfig::default();
+
+    let mut group = c.benchmark_group("evaluate");
+    for (name, input) in [
+        ("short", "Kx7#mQ2!"),
+        ("medium", "Tr0ub4dor&3-Kx7#"),
+        (
+            "long",
+            "Correct-Horse-Battery-Staple-42!-and-then-some-more",
+        ),
+    ] {
+        let password = secret(input);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                evaluate_password_strength(
+                    black_box(&password),
+                    #[cfg(feature = "async")]
+                    None,
+                )
+            })
+        });
+    }
+    group.finish();
+
+    let password = secret("Tr0ub4dor&3-Kx7#");
+    c.bench_function("strength_only/medium", |b| {
+        b.iter(|| strength_only(black_box(&password), &config))
+    });
+
+    let mut group = c.benchmark_group("quick_reject");
+    for (name, input) in [("hit", "password99999"), ("miss", "Tr0ub4dor&3-Kx7#")] {
+        let password = secret(input);
+        group.bench_function(name, |b| {
+            b.iter(|| quick_reject(black_box(&password), &config))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, evaluator);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+//! Minimal wasm-bindgen wrapper exposing the evaluator to JavaScript.
+//!
+//! Build with:
+//!
+//! ```sh
+//! cargo build --example wasm --target wasm32-unknown-unknown --no-default-features --features std
+//! wasm-bindgen --target web target/wasm32-unknown-unknown/debug/examples/wasm.wasm --out-dir pkg
+//! ```
+//!
+//! Then from JavaScript:
+//!
+//! ```js
+//! import init, { load_blacklist, evaluate } from "./pkg/wasm.js";
+//! await init();
+//! load_blacklist(await (await fetch("blacklist.txt")).text());
+//! const { score, reasons } = evaluate("MyP@ssw0rd!");
+//! ```
+
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    use secrecy::SecretString;
+    use wasm_bindgen::prelude::*;
+
+    /// Loads the blacklist from newline-separated text; returns the entry count.
+    #[wasm_bindgen]
+    pub fn load_blacklist(content: &str) -> Result<usize, JsError> {
+        pwd_strength::init_blacklist_from_iter(content.lines())
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Evaluation result returned to JavaScript.
+    #[wasm_bindgen(getter_with_clone)]
+    pub struct Evaluation {
+        pub score: Option<i64>,
+        pub reasons: Vec<String>,
+    }
+
+    /// Evaluates a password and returns its score and reasons.
+    #[wasm_bindgen]
+    pub fn evaluate(password: &str) -> Evaluation {
+        let password = SecretString::new(password.to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = pwd_strength::evaluate_password_strength(&password, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = pwd_strength::evaluate_password_strength(&password);
+
+        Evaluation {
+            score: evaluation.score.map(|s| s.value()),
+            reasons: evaluation.reasons,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * C bindings for pwd-strength (build with `--features ffi`).
+ *
+ * Memory ownership: input strings are borrowed for the duration of each call.
+ * Strings returned through out-parameters are owned by the caller and must be
+ * released with pwd_strength_free_string(), never with free().
+ */
+#ifndef PWD_STRENGTH_H
+#define PWD_STRENGTH_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define PWD_STRENGTH_OK                 0
+#define PWD_STRENGTH_ERR_NULL          -1
+#define PWD_STRENGTH_ERR_UTF8          -2
+#define PWD_STRENGTH_ERR_NOT_FOUND     -3
+#define PWD_STRENGTH_ERR_IO            -4
+#define PWD_STRENGTH_ERR_EMPTY         -5
+#define PWD_STRENGTH_ERR_NOT_EVALUATED -6
+#define PWD_STRENGTH_ERR_PANIC        -99
+
+/* Loads the blacklist from a file. Returns PWD_STRENGTH_OK or an error code. */
+int pwd_strength_init_blacklist(const char *path);
+
+/* Evaluates a password, writing its score (0-100) to out_score. */
+int pwd_strength_evaluate(const char *pwd, int *out_score);
+
+/*
+ * Like pwd_strength_evaluate(), additionally returning the reasons as a
+ * newline-separated string in *out_reasons (if out_reasons is non-NULL).
+ * Free it with pwd_strength_free_string().
+ */
+int pwd_strength_evaluate_with_reasons(const char *pwd, int *out_score, char **out_reasons);
+
+/* Releases a string returned by this library. NULL is a no-op. */
+void pwd_strength_free_string(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PWD_STRENGTH_H */
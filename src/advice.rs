@@ -0,0 +1,290 @@
+//! Tier advice - the cheapest single improvement to reach the next tier.
+//!
+//! A heuristic over the scoring model: each candidate change is applied
+//! to the character statistics and reason codes of the evaluation and
+//! re-scored, without generating or evaluating new passwords.
+
+use std::fmt;
+
+use pwd_types::PasswordStrength;
+use secrecy::SecretString;
+
+use crate::config::EvaluatorConfig;
+use crate::core::WEAK_MAX_SCORE;
+use crate::evaluator::{evaluate_with_codes, score_from_components, strength_of};
+use crate::reason::{CharClass, ReasonCode};
+use crate::stats::CharStats;
+
+/// Lowest score of MEDIUM, STRONG, EPIC and GOD.
+const TIER_THRESHOLDS: [i64; 4] = [50, 70, 85, 96];
+
+/// Most characters `next_tier_advice` suggests adding.
+const MAX_ADDED_CHARACTERS: usize = 32;
+
+/// Reason codes a small edit can clear, e.g. breaking up a sequence.
+const FIXABLE_CODES: [ReasonCode; 6] = [
+    ReasonCode::Repetitive,
+    ReasonCode::Sequential,
+    ReasonCode::KeyboardWalk,
+    ReasonCode::Palindrome,
+    ReasonCode::PhoneNumber,
+    ReasonCode::Date,
+];
+
+/// A single change to a password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Improvement {
+    /// Append this many distinct characters.
+    AddCharacters(usize),
+    /// Add a character of a class the password lacks.
+    AddCharacterClass(CharClass),
+    /// Edit the part of the password reported with this code.
+    Fix(ReasonCode),
+}
+
+impl Improvement {
+    /// Number of characters the change adds or edits.
+    fn cost(self) -> usize {
+        match self {
+            Improvement::AddCharacters(n) => n,
+            Improvement::AddCharacterClass(_) | Improvement::Fix(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for Improvement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Improvement::AddCharacters(1) => f.write_str("add 1 character"),
+            Improvement::AddCharacters(n) => write!(f, "add {} characters", n),
+            Improvement::AddCharacterClass(class) => f.write_str(match class {
+                CharClass::Uppercase => "add an uppercase letter",
+                CharClass::Lowercase => "add a lowercase letter",
+                CharClass::Digit => "add a digit",
+                CharClass::Special => "add a symbol",
+            }),
+            Improvement::Fix(code) => f.write_str(match code {
+                ReasonCode::Repetitive => "break up the repeated characters",
+                ReasonCode::Sequential => "break up the sequence",
+                ReasonCode::KeyboardWalk => "break up the keyboard pattern",
+                ReasonCode::Palindrome => "avoid reading the same backwards",
+                ReasonCode::PhoneNumber => "remove the phone number",
+                ReasonCode::Date => "remove the date",
+                _ => "fix the reported weakness",
+            }),
+        }
+    }
+}
+
+/// How a password could reach the next strength tier.
+#[derive(Debug, PartialEq)]
+pub struct TierAdvice {
+    /// Tier reached with the improvement.
+    pub target: PasswordStrength,
+    /// The change to make.
+    pub improvement: Improvement,
+    /// Estimated score after the change.
+    pub projected_score: i64,
+}
+
+/// Suggests the cheapest single improvement that lifts `password` into
+/// a higher strength tier under `config`.
+///
+/// Candidates are appending characters, adding a missing character class
+/// and clearing a pattern reason (`Improvement::Fix`); the one adding or
+/// editing the fewest characters wins, ties going to the higher projected
+/// score. Blacklist and dictionary reasons have no small fix and are
+/// never cleared. Returns `None` for GOD and unscored passwords, or if no
+/// single improvement reaches the next tier.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// if let Some(advice) = next_tier_advice(&password, &config) {
+///     println!("{} to reach {:?}", advice.improvement, advice.target);
+/// }
+/// ```
+pub fn next_tier_advice(password: &SecretString, config: &EvaluatorConfig) -> Option<TierAdvice> {
+    let (evaluation, stats, mut codes) = evaluate_with_codes(password, config);
+    let current = evaluation.score.as_ref()?.value();
+    let target = *TIER_THRESHOLDS.iter().find(|&&t| t > current)?;
+    codes.retain(|&code| code != ReasonCode::BelowMinScore);
+
+    let mut candidates: Vec<(Improvement, i64)> = Vec::new();
+    for class in [
+        CharClass::Uppercase,
+        CharClass::Lowercase,
+        CharClass::Digit,
+        CharClass::Special,
+    ] {
+        if count_of(&stats, class) == 0 {
+            let (stats, codes) = add_class(&stats, &codes, class, config);
+            candidates.push((
+                Improvement::AddCharacterClass(class),
+                project(&stats, &codes, config),
+            ));
+        }
+    }
+    for code in FIXABLE_CODES
+        .into_iter()
+        .filter(|code| codes.contains(code))
+    {
+        let fixed: Vec<ReasonCode> = codes.iter().copied().filter(|&c| c != code).collect();
+        candidates.push((Improvement::Fix(code), project(&stats, &fixed, config)));
+    }
+    let room = config
+        .max_length
+        .saturating_sub(stats.byte_len)
+        .min(MAX_ADDED_CHARACTERS);
+    if let Some((n, score)) = (1..=room)
+        .map(|n| {
+            let (stats, codes) = add_characters(&stats, &codes, n, config);
+            (n, project(&stats, &codes, config))
+        })
+        .find(|&(_, score)| score >= target)
+    {
+        candidates.push((Improvement::AddCharacters(n), score));
+    }
+
+    candidates
+        .into_iter()
+        .filter(|&(_, score)| score >= target)
+        .min_by_key(|&(improvement, score)| (improvement.cost(), -score))
+        .map(|(improvement, projected_score)| TierAdvice {
+            target: strength_of(projected_score),
+            improvement,
+            projected_score,
+        })
+}
+
+fn count_of(stats: &CharStats, class: CharClass) -> usize {
+    match class {
+        CharClass::Uppercase => stats.counts.upper,
+        CharClass::Lowercase => stats.counts.lower,
+        CharClass::Digit => stats.counts.digit,
+        CharClass::Special => stats.counts.special,
+    }
+}
+
+/// `stats` and `codes` after appending one new character of `class`.
+fn add_class(
+    stats: &CharStats,
+    codes: &[ReasonCode],
+    class: CharClass,
+    config: &EvaluatorConfig,
+) -> (CharStats, Vec<ReasonCode>) {
+    let (mut stats, mut codes) = add_characters(stats, codes, 1, config);
+    match class {
+        CharClass::Uppercase => stats.counts.upper += 1,
+        CharClass::Lowercase => stats.counts.lower += 1,
+        CharClass::Digit => stats.counts.digit += 1,
+        CharClass::Special => stats.counts.special += 1,
+    }
+    if config
+        .required_classes
+        .iter()
+        .all(|&c| count_of(&stats, c) > 0)
+    {
+        codes.retain(|&code| code != ReasonCode::MissingCharacterTypes);
+    }
+    (stats, codes)
+}
+
+/// `stats` and `codes` after appending `n` new distinct characters of
+/// classes the password already has.
+fn add_characters(
+    stats: &CharStats,
+    codes: &[ReasonCode],
+    n: usize,
+    config: &EvaluatorConfig,
+) -> (CharStats, Vec<ReasonCode>) {
+    let mut stats = stats.clone();
+    stats.byte_len += n;
+    stats.char_len += n;
+    stats.unique += n;
+    let mut codes = codes.to_vec();
+    if stats.byte_len >= config.min_length {
+        codes.retain(|&code| code != ReasonCode::TooShort);
+    }
+    if (stats.unique as f64 / stats.char_len as f64) >= config.min_unique_ratio {
+        codes.retain(|&code| code != ReasonCode::LowUniqueness);
+    }
+    (stats, codes)
+}
+
+/// Score of a candidate, with the `min_score` cap of the evaluator.
+fn project(stats: &CharStats, codes: &[ReasonCode], config: &EvaluatorConfig) -> i64 {
+    let score = score_from_components(stats, codes, config);
+    match config.min_score {
+        Some(min_score) if score < min_score => score.min(WEAK_MAX_SCORE),
+        _ => score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::evaluate_password_strength_with_config;
+    use crate::sections::{secret, setup_blacklist};
+    use serial_test::serial;
+
+    fn score(password: &str, config: &EvaluatorConfig) -> i64 {
+        evaluate_password_strength_with_config(
+            &secret(password),
+            config,
+            #[cfg(feature = "async")]
+            None,
+        )
+        .score
+        .unwrap()
+        .value()
+    }
+
+    #[test]
+    #[serial]
+    fn test_medium_password_needs_length_for_strong() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default();
+        assert_eq!(
+            strength_of(score("Kx7#mQ2!", &config)),
+            PasswordStrength::MEDIUM
+        );
+
+        let advice = next_tier_advice(&secret("Kx7#mQ2!"), &config).unwrap();
+        assert_eq!(advice.improvement, Improvement::AddCharacters(2));
+        assert_eq!(advice.target, PasswordStrength::STRONG);
+        assert_eq!(advice.improvement.to_string(), "add 2 characters");
+
+        // The projection matches the real score of the longer password
+        assert_eq!(score("Kx7#mQ2!vR", &config), advice.projected_score);
+        assert!(score("Kx7#mQ2!v", &config) < 70);
+    }
+
+    #[test]
+    #[serial]
+    fn test_missing_class_is_cheapest() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default();
+        let advice = next_tier_advice(&secret("Kx7mQ2vRpL"), &config).unwrap();
+        assert_eq!(
+            advice.improvement,
+            Improvement::AddCharacterClass(CharClass::Special)
+        );
+        assert_eq!(advice.improvement.to_string(), "add a symbol");
+        assert_eq!(score("Kx7mQ2vRpL!", &config), advice.projected_score);
+        assert_ne!(advice.target, PasswordStrength::WEAK);
+    }
+
+    #[test]
+    #[serial]
+    fn test_no_advice_at_the_top() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default();
+        let god = secret("Kx7#mQ2!vR9$wL4@zT8&bN5%");
+        assert_eq!(
+            strength_of(score("Kx7#mQ2!vR9$wL4@zT8&bN5%", &config)),
+            PasswordStrength::GOD
+        );
+        assert_eq!(next_tier_advice(&god, &config), None);
+    }
+}
@@ -0,0 +1,159 @@
+//! Command-line password strength checker.
+//!
+//! Reads a password from the first argument or, preferably, from stdin
+//! (which keeps it out of the shell history), loads the blacklist from
+//! `PWD_BLACKLIST_PATH` and prints the score, strength and reasons.
+//!
+//! Exit codes:
+//! - `0`: password is at least MEDIUM
+//! - `1`: password is WEAK or could not be evaluated
+//! - `2`: usage or input error
+
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+use pwd_strength::{
+    PasswordEvaluation, PasswordStrength, evaluate_password_strength, init_blacklist,
+};
+use secrecy::SecretString;
+
+const USAGE: &str = "\
+Usage: pwd-strength [--json] [PASSWORD]
+
+Evaluates password strength. If PASSWORD is omitted it is read from stdin.
+
+Options:
+  --json      Print the result as JSON
+  -h, --help  Print this help
+
+Environment:
+  PWD_BLACKLIST_PATH  Path to the blacklist file";
+
+struct Args {
+    json: bool,
+    password: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        json: false,
+        password: None,
+    };
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => args.json = true,
+            "-h" | "--help" => return Err(String::new()),
+            s if s.starts_with("--") => return Err(format!("unknown option: {}", s)),
+            _ if args.password.is_some() => return Err("too many arguments".to_string()),
+            _ => args.password = Some(arg),
+        }
+    }
+    Ok(args)
+}
+
+fn read_stdin_password() -> std::io::Result<String> {
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    let trimmed_len = line.trim_end_matches(['\n', '\r']).len();
+    line.truncate(trimmed_len);
+    Ok(line)
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn print_json(evaluation: &PasswordEvaluation, out: &mut impl Write) -> std::io::Result<()> {
+    let score = evaluation
+        .score
+        .as_ref()
+        .map(|s| s.value().to_string())
+        .unwrap_or_else(|| "null".to_string());
+    let strength = json_escape(&format!("{:?}", evaluation.strength()));
+    let reasons: Vec<String> = evaluation.reasons.iter().map(|r| json_escape(r)).collect();
+    writeln!(
+        out,
+        "{{\"score\":{},\"strength\":{},\"reasons\":[{}]}}",
+        score,
+        strength,
+        reasons.join(",")
+    )
+}
+
+fn print_text(evaluation: &PasswordEvaluation, out: &mut impl Write) -> std::io::Result<()> {
+    match &evaluation.score {
+        Some(score) => writeln!(out, "Score: {}", score.value())?,
+        None => writeln!(out, "Score: -")?,
+    }
+    writeln!(out, "Strength: {:?}", evaluation.strength())?;
+    for reason in &evaluation.reasons {
+        writeln!(out, "- {}", reason)?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) if msg.is_empty() => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("error: {}\n\n{}", msg, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let password = match args.password {
+        Some(p) => p,
+        None => match read_stdin_password() {
+            Ok(p) => p,
+            Err(e) => {
+                eprintln!("error: failed to read password from stdin: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+    };
+    let password = SecretString::new(password.into());
+
+    if let Err(e) = init_blacklist() {
+        eprintln!("warning: blacklist not loaded: {}", e);
+    }
+
+    #[cfg(feature = "async")]
+    let evaluation = evaluate_password_strength(&password, None);
+
+    #[cfg(not(feature = "async"))]
+    let evaluation = evaluate_password_strength(&password);
+
+    let mut stdout = std::io::stdout().lock();
+    let printed = if args.json {
+        print_json(&evaluation, &mut stdout)
+    } else {
+        print_text(&evaluation, &mut stdout)
+    };
+    if let Err(e) = printed {
+        eprintln!("error: failed to write output: {}", e);
+        return ExitCode::from(2);
+    }
+
+    match evaluation.strength() {
+        PasswordStrength::WEAK | PasswordStrength::NotEvaluated => ExitCode::from(1),
+        _ => ExitCode::SUCCESS,
+    }
+}
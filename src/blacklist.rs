@@ -1,14 +1,85 @@
 //! Blacklist management module
 //!
 //! Handles loading and querying the password blacklist.
+//!
+//! The list lives behind a global `RwLock`. A panic in another thread
+//! while it holds the lock does not poison later evaluations: writers only
+//! ever swap in a complete set, so the guarded value is always consistent
+//! and poisoned locks are recovered rather than unwrapped.
 
 use std::collections::HashSet;
+use std::fmt;
 use std::path::PathBuf;
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 use thiserror::Error;
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::config::DEFAULT_MAX_LENGTH;
+use crate::similarity::bounded_levenshtein;
 
 static COMMON_PASSWORDS: RwLock<Option<HashSet<String>>> = RwLock::new(None);
 
+/// Held by initializers while they load, so concurrent calls read the
+/// source at most once. Lookups never take it.
+static INIT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Blacklist path used when neither an override nor `PWD_BLACKLIST_PATH` is set.
+pub const DEFAULT_BLACKLIST_PATH: &str = "./assets/blacklist.txt";
+
+/// Path set with `set_default_blacklist_path`, if any.
+static PATH_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Default maximum length in bytes of a blacklist entry.
+pub const DEFAULT_MAX_ENTRY_LENGTH: usize = 256;
+
+/// Default for `BlacklistLoadOptions::min_reload_entries`.
+pub const DEFAULT_MIN_RELOAD_ENTRIES: usize = 1;
+
+/// Default for `BlacklistLoadOptions::min_reload_percent`.
+pub const DEFAULT_MIN_RELOAD_PERCENT: usize = 50;
+
+/// Options for loading a blacklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlacklistLoadOptions {
+    /// Entries longer than this many bytes (after trimming) are skipped.
+    ///
+    /// Real passwords this long are not worth storing, and a malformed file
+    /// (e.g. a dump without newlines) would otherwise end up in the set as
+    /// one huge entry.
+    pub max_entry_length: usize,
+    /// Fewest entries `reload_blacklist_from_path_with_options` accepts
+    /// before replacing the loaded list. Ignored by the initializers.
+    pub min_reload_entries: usize,
+    /// Smallest size, in percent of the loaded list, that
+    /// `reload_blacklist_from_path_with_options` accepts, so a truncated
+    /// file cannot replace a large list. Ignored by the initializers.
+    pub min_reload_percent: usize,
+}
+
+impl Default for BlacklistLoadOptions {
+    fn default() -> Self {
+        Self {
+            max_entry_length: DEFAULT_MAX_ENTRY_LENGTH,
+            min_reload_entries: DEFAULT_MIN_RELOAD_ENTRIES,
+            min_reload_percent: DEFAULT_MIN_RELOAD_PERCENT,
+        }
+    }
+}
+
+/// Outcome of a blacklist load.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlacklistLoadReport {
+    /// Number of distinct entries in the blacklist.
+    pub loaded: usize,
+    /// Number of lines skipped for exceeding `max_entry_length`.
+    pub skipped_too_long: usize,
+    /// Number of lines skipped for containing invalid UTF-8.
+    pub skipped_invalid_utf8: usize,
+    /// Number of lines dropped as duplicates of another line once
+    /// canonicalized (e.g. `"Password\r"` and `"password"`).
+    pub merged_duplicates: usize,
+}
+
 #[derive(Error, Debug)]
 pub enum BlacklistError {
     #[error("Blacklist file not found: {0}")]
@@ -17,25 +88,67 @@ pub enum BlacklistError {
     ReadError(#[from] std::io::Error),
     #[error("Blacklist file is empty")]
     EmptyFile,
+    #[error("Blacklist file has {loaded} entries, fewer than the {minimum} required to reload")]
+    SuspiciouslySmall { loaded: usize, minimum: usize },
+    #[error("Blacklist source failed: {0}")]
+    Source(#[source] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Blacklist not loaded")]
+    NotLoaded,
 }
 
 /// Returns the blacklist file path.
 ///
 /// Priority:
-/// 1. Environment variable `PWD_BLACKLIST_PATH`
-/// 2. Default path `./assets/blacklist.txt`
+/// 1. Path set with `set_default_blacklist_path`
+/// 2. Environment variable `PWD_BLACKLIST_PATH`
+/// 3. `DEFAULT_BLACKLIST_PATH` (`./assets/blacklist.txt`)
 pub fn get_blacklist_path() -> PathBuf {
+    if let Some(path) = PATH_OVERRIDE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+    {
+        return path.clone();
+    }
     std::env::var("PWD_BLACKLIST_PATH")
         .map(PathBuf::from)
-        .unwrap_or_else(|_| PathBuf::from("./assets/blacklist.txt"))
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_BLACKLIST_PATH))
+}
+
+/// Sets the path `init_blacklist` loads from.
+///
+/// Takes precedence over `PWD_BLACKLIST_PATH`, so libraries can choose the
+/// path without mutating the process environment.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// pwd_strength::set_default_blacklist_path("/etc/myapp/blacklist.txt");
+/// pwd_strength::init_blacklist()?;
+/// # Ok::<(), pwd_strength::BlacklistError>(())
+/// ```
+pub fn set_default_blacklist_path<P: Into<PathBuf>>(path: P) {
+    *PATH_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = Some(path.into());
+}
+
+/// Removes the path set with `set_default_blacklist_path`.
+///
+/// `get_blacklist_path` falls back to `PWD_BLACKLIST_PATH` and the default.
+pub fn clear_default_blacklist_path() {
+    *PATH_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = None;
 }
 
 /// Initializes the password blacklist from external file.
 ///
-/// # Environment Variable
+/// # Path Resolution
+///
+/// Loads from the path returned by `get_blacklist_path`: the path set with
+/// `set_default_blacklist_path`, else `PWD_BLACKLIST_PATH`, else
+/// `DEFAULT_BLACKLIST_PATH` (`./assets/blacklist.txt`).
 ///
-/// Set `PWD_BLACKLIST_PATH` to specify a custom blacklist file location.
-/// If not set, defaults to `./assets/blacklist.txt`.
+/// The file is read synchronously. With the `async` feature, loading from
+/// inside a tokio runtime emits a tracing warning; use
+/// `init_blacklist_async` there instead.
 ///
 /// # Errors
 ///
@@ -61,6 +174,10 @@ pub fn init_blacklist() -> Result<usize, BlacklistError> {
 
 /// Initializes the password blacklist from a specific file path.
 ///
+/// Entries longer than `DEFAULT_MAX_ENTRY_LENGTH` bytes are skipped; use
+/// `init_blacklist_from_path_with_options` to change the cap or to learn
+/// how many lines were skipped.
+///
 /// Use this when you need to pass the path directly (e.g., from Dioxus asset system)
 /// instead of relying on environment variables.
 ///
@@ -82,24 +199,171 @@ pub fn init_blacklist() -> Result<usize, BlacklistError> {
 /// let asset_path = BLACKLIST_ASSET.to_string();
 /// pwd_strength::init_blacklist_from_path(&asset_path)?;
 /// ```
-pub fn init_blacklist_from_path<P: AsRef<std::path::Path>>(path: P) -> Result<usize, BlacklistError> {
-    // Idempotente: se gia inizializzata, ritorna subito
-    {
-        let guard = COMMON_PASSWORDS.read().unwrap();
-        if guard.is_some() {
-            return Ok(guard.as_ref().map(|s| s.len()).unwrap_or(0));
-        }
-    }
+pub fn init_blacklist_from_path<P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<usize, BlacklistError> {
+    init_blacklist_from_path_with_options(path, &BlacklistLoadOptions::default())
+        .map(|report| report.loaded)
+}
 
-    let path = path.as_ref();
+/// Initializes the password blacklist from a file path with custom options.
+///
+/// If the blacklist is already initialized, returns its size without
+/// reloading (the skip counters are then `0`).
+///
+/// Lines that are not valid UTF-8, common in scraped lists, are skipped
+/// and counted in `skipped_invalid_utf8` instead of failing the load.
+///
+/// # Errors
+///
+/// Same as `init_blacklist_from_path`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = BlacklistLoadOptions { max_entry_length: 64, ..Default::default() };
+/// let report = pwd_strength::init_blacklist_from_path_with_options("breach.txt", &options)?;
+/// println!("{} loaded, {} skipped", report.loaded, report.skipped_too_long);
+/// ```
+pub fn init_blacklist_from_path_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &BlacklistLoadOptions,
+) -> Result<BlacklistLoadReport, BlacklistError> {
+    init_once(
+        |count| BlacklistLoadReport {
+            loaded: count,
+            ..Default::default()
+        },
+        || {
+            #[cfg(feature = "async")]
+            warn_if_in_runtime();
+            load_from_path(path.as_ref(), options, 0)
+        },
+    )
+}
+
+/// Reloads the blacklist from the path returned by `get_blacklist_path`,
+/// replacing the loaded list.
+///
+/// # Errors
+///
+/// Same as `reload_blacklist_from_path_with_options`.
+pub fn reload_blacklist() -> Result<usize, BlacklistError> {
+    reload_blacklist_from_path_with_options(get_blacklist_path(), &BlacklistLoadOptions::default())
+        .map(|report| report.loaded)
+}
+
+/// Reloads the blacklist from `path`, replacing the loaded list (or
+/// installing one if none is loaded).
+///
+/// The file is parsed completely before the swap, and lookups keep using
+/// the previous list until then. A file that is empty or yields fewer
+/// entries than either `options.min_reload_entries` or
+/// `options.min_reload_percent` of the loaded list (by default half of
+/// it), i.e. fewer than the larger of the two, is rejected and the
+/// previous list stays loaded, so a file truncated by accident cannot
+/// replace it.
+///
+/// # Errors
+///
+/// Same as `init_blacklist_from_path`, plus
+/// `BlacklistError::SuspiciouslySmall` for a file below the minimum.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let options = BlacklistLoadOptions { min_reload_entries: 10_000, min_reload_percent: 90, ..Default::default() };
+/// if let Err(e) = pwd_strength::reload_blacklist_from_path_with_options("breach.txt", &options) {
+///     eprintln!("keeping the current blacklist: {e}");
+/// }
+/// ```
+pub fn reload_blacklist_from_path_with_options<P: AsRef<std::path::Path>>(
+    path: P,
+    options: &BlacklistLoadOptions,
+) -> Result<BlacklistLoadReport, BlacklistError> {
+    let _init = INIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    #[cfg(feature = "async")]
+    warn_if_in_runtime();
+    let current = loaded_count().unwrap_or(0);
+    let relative = (current * options.min_reload_percent).div_ceil(100);
+    load_from_path(
+        path.as_ref(),
+        options,
+        options.min_reload_entries.max(relative),
+    )
+}
+
+/// Async counterpart of `init_blacklist` (`async` feature only).
+///
+/// Resolves the path like `init_blacklist` and reads the file on tokio's
+/// blocking pool, so initializing from inside a runtime does not stall
+/// the executor.
+///
+/// # Errors
+///
+/// Same as `init_blacklist`, plus `BlacklistError::Source` if the
+/// blocking task panics.
+#[cfg(feature = "async")]
+pub async fn init_blacklist_async() -> Result<usize, BlacklistError> {
+    init_blacklist_from_path_async(get_blacklist_path()).await
+}
 
+/// Async counterpart of `init_blacklist_from_path` (`async` feature only).
+///
+/// # Errors
+///
+/// Same as `init_blacklist_async`.
+#[cfg(feature = "async")]
+pub async fn init_blacklist_from_path_async<P: Into<PathBuf>>(
+    path: P,
+) -> Result<usize, BlacklistError> {
+    let path = path.into();
+    tokio::task::spawn_blocking(move || {
+        init_once(
+            |count| count,
+            || {
+                load_from_path(&path, &BlacklistLoadOptions::default(), 0)
+                    .map(|report| report.loaded)
+            },
+        )
+    })
+    .await
+    .map_err(|e| BlacklistError::Source(Box::new(e)))?
+}
+
+/// Warns, with the `tracing` feature, when blocking file I/O is about to
+/// run on an async runtime thread. Returns `true` if inside a runtime.
+///
+/// Points callers of the synchronous initializers at
+/// `init_blacklist_async`.
+#[cfg(feature = "async")]
+fn warn_if_in_runtime() -> bool {
+    let in_runtime = tokio::runtime::Handle::try_current().is_ok();
+    #[cfg(feature = "tracing")]
+    if in_runtime {
+        tracing::warn!(
+            "Blacklist file read blocks an async runtime thread; use init_blacklist_async"
+        );
+    }
+    in_runtime
+}
+
+/// Loads the blacklist file at `path`; see `init_blacklist_from_path_with_options`.
+///
+/// Fails without touching the loaded list if the file yields fewer than
+/// `min_entries` entries.
+fn load_from_path(
+    path: &std::path::Path,
+    options: &BlacklistLoadOptions,
+    min_entries: usize,
+) -> Result<BlacklistLoadReport, BlacklistError> {
     if !path.exists() {
         #[cfg(feature = "tracing")]
         tracing::error!("Blacklist initialization FAILED: FileNotFound {:?}", path);
         return Err(BlacklistError::FileNotFound(path.to_path_buf()));
     }
 
-    let content = std::fs::read_to_string(&path)?;
+    let (content, skipped_invalid_utf8) = decode_lines(std::fs::read(path)?);
 
     if content.trim().is_empty() {
         #[cfg(feature = "tracing")]
@@ -107,49 +371,567 @@ pub fn init_blacklist_from_path<P: AsRef<std::path::Path>>(path: P) -> Result<us
         return Err(BlacklistError::EmptyFile);
     }
 
-    let set: HashSet<String> = content
-        .lines()
-        .map(|l| l.trim().to_lowercase())
+    let (set, skipped_too_long, merged_duplicates) =
+        build_set_from_content(&content, options.max_entry_length);
+    if set.len() < min_entries {
+        #[cfg(feature = "tracing")]
+        tracing::error!(
+            "Blacklist load FAILED: {} entries from {:?}, {} required",
+            set.len(),
+            path,
+            min_entries
+        );
+        return Err(BlacklistError::SuspiciouslySmall {
+            loaded: set.len(),
+            minimum: min_entries,
+        });
+    }
+    let count = install(set);
+
+    #[cfg(feature = "tracing")]
+    if skipped_too_long > 0 {
+        tracing::warn!(
+            "Blacklist: skipped {} entries longer than {} bytes",
+            skipped_too_long,
+            options.max_entry_length
+        );
+    }
+    #[cfg(feature = "tracing")]
+    if skipped_invalid_utf8 > 0 {
+        tracing::warn!(
+            "Blacklist: skipped {} lines with invalid UTF-8",
+            skipped_invalid_utf8
+        );
+    }
+    #[cfg(feature = "tracing")]
+    tracing::info!("Blacklist initialized: {} passwords from {:?}", count, path);
+
+    Ok(BlacklistLoadReport {
+        loaded: count,
+        skipped_too_long,
+        skipped_invalid_utf8,
+        merged_duplicates,
+    })
+}
+
+/// Initializes the password blacklist from any reader, one entry per line.
+///
+/// Unlike `init_blacklist_from_path`, this does not touch the filesystem,
+/// so it also works on targets without one (e.g. `wasm32-unknown-unknown`
+/// with the list fetched or embedded by the host).
+///
+/// Lines that are not valid UTF-8 are skipped.
+///
+/// # Errors
+///
+/// Returns error if:
+/// - The reader fails
+/// - The content is empty
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let bytes: &[u8] = include_bytes!("../assets/blacklist.txt");
+/// pwd_strength::init_blacklist_from_reader(bytes)?;
+/// ```
+pub fn init_blacklist_from_reader<R: std::io::Read>(reader: R) -> Result<usize, BlacklistError> {
+    init_once(|count| count, || load_from_reader(reader))
+}
+
+/// Loads the blacklist from `reader`; see `init_blacklist_from_reader`.
+fn load_from_reader<R: std::io::Read>(mut reader: R) -> Result<usize, BlacklistError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let (content, _skipped_invalid_utf8) = decode_lines(bytes);
+
+    if content.trim().is_empty() {
+        #[cfg(feature = "tracing")]
+        tracing::error!("Blacklist initialization FAILED: Empty reader");
+        return Err(BlacklistError::EmptyFile);
+    }
+
+    let (set, _, _) = build_set_from_content(&content, DEFAULT_MAX_ENTRY_LENGTH);
+    let count = install(set);
+
+    #[cfg(feature = "tracing")]
+    if _skipped_invalid_utf8 > 0 {
+        tracing::warn!(
+            "Blacklist: skipped {} lines with invalid UTF-8",
+            _skipped_invalid_utf8
+        );
+    }
+    #[cfg(feature = "tracing")]
+    tracing::info!("Blacklist initialized: {} passwords from reader", count);
+
+    Ok(count)
+}
+
+/// Initializes the password blacklist from in-memory entries.
+///
+/// Entries are trimmed, lowercased and deduplicated exactly like file lines.
+///
+/// # Errors
+///
+/// Returns `BlacklistError::EmptyFile` if no non-blank entry is provided.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pwd_strength::init_blacklist_from_iter(["password", "123456", "qwerty"])?;
+/// ```
+pub fn init_blacklist_from_iter<I, S>(entries: I) -> Result<usize, BlacklistError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    init_once(|count| count, || load_from_iter(entries))
+}
+
+/// Loads the blacklist from `entries`; see `init_blacklist_from_iter`.
+fn load_from_iter<I, S>(entries: I) -> Result<usize, BlacklistError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let (set, _, _) = build_set(entries, DEFAULT_MAX_ENTRY_LENGTH);
+    if set.is_empty() {
+        #[cfg(feature = "tracing")]
+        tracing::error!("Blacklist initialization FAILED: no entries");
+        return Err(BlacklistError::EmptyFile);
+    }
+
+    let count = install(set);
+
+    #[cfg(feature = "tracing")]
+    tracing::info!("Blacklist initialized: {} passwords from iterator", count);
+
+    Ok(count)
+}
+
+/// Adds the entries of a file to the blacklist, one entry per line.
+///
+/// Entries are normalized like `init_blacklist_from_path` and unioned into
+/// the current set, so a base list can be combined with supplemental
+/// breach lists. The base list must be loaded first: extending an
+/// uninitialized blacklist would make the later `init_blacklist` skip the
+/// base file. Waits for a concurrent initialization or reload to finish.
+///
+/// # Errors
+///
+/// Returns error if:
+/// - No blacklist is loaded yet (`BlacklistError::NotLoaded`)
+/// - File does not exist
+/// - File cannot be read
+/// - File is empty
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pwd_strength::init_blacklist_from_path("base.txt")?;
+/// let total = pwd_strength::extend_blacklist_from_path("breach-2024.txt")?;
+/// ```
+pub fn extend_blacklist_from_path<P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<usize, BlacklistError> {
+    let path = path.as_ref();
+    let _init = INIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if !is_blacklist_loaded() {
+        return Err(BlacklistError::NotLoaded);
+    }
+    if !path.exists() {
+        return Err(BlacklistError::FileNotFound(path.to_path_buf()));
+    }
+
+    let (content, _) = decode_lines(std::fs::read(path)?);
+    if content.trim().is_empty() {
+        return Err(BlacklistError::EmptyFile);
+    }
+
+    let (set, _, _) = build_set_from_content(&content, DEFAULT_MAX_ENTRY_LENGTH);
+    let count = merge(set)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!("Blacklist extended: {} passwords after {:?}", count, path);
+
+    Ok(count)
+}
+
+/// Adds in-memory entries to the blacklist.
+///
+/// Like `extend_blacklist_from_path`, returning the new total.
+///
+/// # Errors
+///
+/// Returns `BlacklistError::NotLoaded` if no blacklist is loaded yet, and
+/// `BlacklistError::EmptyFile` if no non-blank entry is provided.
+pub fn extend_blacklist_from_iter<I, S>(entries: I) -> Result<usize, BlacklistError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let _init = INIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if !is_blacklist_loaded() {
+        return Err(BlacklistError::NotLoaded);
+    }
+    let (set, _, _) = build_set(entries, DEFAULT_MAX_ENTRY_LENGTH);
+    if set.is_empty() {
+        return Err(BlacklistError::EmptyFile);
+    }
+    merge(set)
+}
+
+/// Runs `load` unless the blacklist is already initialized, in which case
+/// `already_loaded` builds the result from the current size.
+///
+/// The check is repeated under `INIT_LOCK`, so when several threads
+/// initialize at once only the first one loads and the others return the
+/// size it installed.
+fn init_once<T>(
+    already_loaded: impl Fn(usize) -> T,
+    load: impl FnOnce() -> Result<T, BlacklistError>,
+) -> Result<T, BlacklistError> {
+    if let Some(count) = loaded_count() {
+        return Ok(already_loaded(count));
+    }
+    let _init = INIT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(count) = loaded_count() {
+        return Ok(already_loaded(count));
+    }
+    load()
+}
+
+/// Returns the number of loaded entries, or `None` if not yet initialized.
+fn loaded_count() -> Option<usize> {
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref().map(|s| s.len())
+}
+
+/// Decodes blacklist bytes, dropping lines that are not valid UTF-8.
+///
+/// Returns the content and the number of dropped lines. Valid input is
+/// converted without copying.
+fn decode_lines(bytes: Vec<u8>) -> (String, usize) {
+    let bytes = match String::from_utf8(bytes) {
+        Ok(content) => return (content, 0),
+        Err(e) => e.into_bytes(),
+    };
+    let mut skipped = 0;
+    let mut content = String::with_capacity(bytes.len());
+    for line in bytes.split(|&b| b == b'\n') {
+        match std::str::from_utf8(line) {
+            Ok(line) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            Err(_) => skipped += 1,
+        }
+    }
+    (content, skipped)
+}
+
+/// Normalizes raw entries into the lookup set.
+///
+/// Returns the set, the number of entries skipped for exceeding
+/// `max_entry_length` bytes and the number merged into an identical
+/// canonical entry.
+fn build_set<I, S>(entries: I, max_entry_length: usize) -> (HashSet<String>, usize, usize)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut skipped = 0;
+    let mut accepted = 0;
+    let set: HashSet<String> = entries
+        .into_iter()
+        .map(|l| canonicalize_entry(l.as_ref()))
         .filter(|l| !l.is_empty())
+        .filter(|l| {
+            let too_long = l.len() > max_entry_length;
+            skipped += usize::from(too_long);
+            accepted += usize::from(!too_long);
+            !too_long
+        })
         .collect();
+    let merged = accepted - set.len();
+    (set, skipped, merged)
+}
 
-    let count = set.len();
+/// Builds the lookup set from file content, one entry per line.
+///
+/// With the `rayon` feature, lines are normalized in parallel; the result
+/// is identical to the sequential `build_set`.
+fn build_set_from_content(
+    content: &str,
+    max_entry_length: usize,
+) -> (HashSet<String>, usize, usize) {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        let entries: Vec<String> = content
+            .par_lines()
+            .map(canonicalize_entry)
+            .filter(|l| !l.is_empty())
+            .collect();
+        // Canonicalization is the costly part; counting and hashing stay sequential
+        let skipped = entries
+            .iter()
+            .filter(|l| l.len() > max_entry_length)
+            .count();
+        let accepted = entries.len() - skipped;
+        let set: HashSet<String> = entries
+            .into_iter()
+            .filter(|l| l.len() <= max_entry_length)
+            .collect();
+        let merged = accepted - set.len();
+        (set, skipped, merged)
+    }
+    #[cfg(not(feature = "rayon"))]
     {
-        let mut guard = COMMON_PASSWORDS.write().unwrap();
-        *guard = Some(set);
+        build_set(content.lines(), max_entry_length)
     }
+}
 
-    #[cfg(feature = "tracing")]
-    tracing::info!("Blacklist initialized: {} passwords from {:?}", count, path);
+/// Stores `set` as the active blacklist, returning its size.
+fn install(set: HashSet<String>) -> usize {
+    let count = set.len();
+    let mut guard = COMMON_PASSWORDS.write().unwrap_or_else(|e| e.into_inner());
+    *guard = Some(set);
+    count
+}
 
-    Ok(count)
+/// Unions `set` into the active blacklist, returning the new size.
+///
+/// Callers hold `INIT_LOCK`, so the list cannot be replaced in between.
+fn merge(set: HashSet<String>) -> Result<usize, BlacklistError> {
+    let mut guard = COMMON_PASSWORDS.write().unwrap_or_else(|e| e.into_inner());
+    let existing = guard.as_mut().ok_or(BlacklistError::NotLoaded)?;
+    existing.extend(set);
+    Ok(existing.len())
+}
+
+/// Canonical form of a raw blacklist line.
+///
+/// Drops a leading UTF-8 BOM and any control characters (`\r`, tabs,
+/// NUL), trims surrounding whitespace and applies `normalize_entry`, so
+/// `"\u{FEFF}Password\r"` and `"password\t"` both become `"password"`.
+fn canonicalize_entry(raw: &str) -> String {
+    let raw = raw.strip_prefix('\u{FEFF}').unwrap_or(raw);
+    if !raw.contains(char::is_control) {
+        return normalize_entry(raw.trim());
+    }
+    let cleaned: String = raw.chars().filter(|c| !c.is_control()).collect();
+    normalize_entry(cleaned.trim())
+}
+
+/// Canonical form used for blacklist storage and lookup.
+///
+/// Entries are lowercased and, with the `unicode-normalization` feature,
+/// brought to NFC so that composed and decomposed forms match.
+fn normalize_entry(entry: &str) -> String {
+    #[cfg(feature = "unicode-normalization")]
+    let entry = crate::normalize::normalize(entry);
+    entry.to_lowercase()
+}
+
+/// `normalize_entry` for a password, zeroized on drop.
+fn normalize_password(password: &str) -> Zeroizing<String> {
+    #[cfg(feature = "unicode-normalization")]
+    let password = Zeroizing::new(crate::normalize::normalize(password).into_owned());
+    Zeroizing::new(password.to_lowercase())
 }
 
 /// Returns a cloned reference to the loaded blacklist.
 ///
 /// Returns `None` if `init_blacklist()` has not been called.
 pub fn get_blacklist() -> Option<HashSet<String>> {
-    let guard = COMMON_PASSWORDS.read().unwrap();
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
     guard.clone()
 }
 
+/// Runs `f` on the loaded blacklist under the read lock, returning its
+/// result.
+///
+/// Unlike `get_blacklist`, the set is not cloned, so counting, sampling or
+/// exporting entries costs no extra memory. Returns `None` if the
+/// blacklist is not initialized. Reloading waits until `f` returns, so
+/// keep it short and do not call the blacklist loaders from inside it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let numeric = pwd_strength::with_blacklist(|bl| {
+///     bl.iter().filter(|e| e.chars().all(|c| c.is_ascii_digit())).count()
+/// });
+/// ```
+pub fn with_blacklist<F, R>(f: F) -> Option<R>
+where
+    F: FnOnce(&HashSet<String>) -> R,
+{
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref().map(f)
+}
+
+/// Returns `true` if the global blacklist has been initialized.
+pub(crate) fn is_blacklist_loaded() -> bool {
+    COMMON_PASSWORDS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some()
+}
+
 /// Checks if a password is in the blacklist.
 ///
 /// Returns `true` if password is in the blacklist (case-insensitive).
 /// Returns `false` if blacklist is not initialized or password is not found.
 pub fn is_blacklisted(password: &str) -> bool {
-    let guard = COMMON_PASSWORDS.read().unwrap();
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
     guard
         .as_ref()
-        .map(|bl| bl.contains(&password.to_lowercase()))
+        .map(|bl| bl.contains(normalize_password(password).as_str()))
         .unwrap_or(false)
 }
 
+/// `is_blacklisted` without a heap copy for ASCII passwords.
+///
+/// Normalization only changes the case of ASCII, so uppercase letters are
+/// folded into a stack buffer that is zeroed after the lookup. Non-ASCII
+/// passwords, and ASCII ones longer than `DEFAULT_MAX_LENGTH` bytes, take
+/// the normalizing path of `is_blacklisted`.
+pub(crate) fn is_blacklisted_fast(password: &str) -> bool {
+    if !password.is_ascii() || password.len() > DEFAULT_MAX_LENGTH {
+        return is_blacklisted(password);
+    }
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
+    let Some(bl) = guard.as_ref() else {
+        return false;
+    };
+    if !password.bytes().any(|b| b.is_ascii_uppercase()) {
+        return bl.contains(password);
+    }
+    let mut buf = [0u8; DEFAULT_MAX_LENGTH];
+    let folded = &mut buf[..password.len()];
+    folded.copy_from_slice(password.as_bytes());
+    folded.make_ascii_lowercase();
+    // Lowercased ASCII is still valid UTF-8
+    let found = std::str::from_utf8(folded).is_ok_and(|folded| bl.contains(folded));
+    buf.zeroize();
+    found
+}
+
+/// Checks if the password contains a blacklisted entry as a substring.
+///
+/// Only entries of at least `min_entry_length` bytes are considered, so
+/// short entries such as `"a"` do not match every password. Matching is
+/// case-insensitive, like `is_blacklisted`.
+pub(crate) fn contains_blacklisted_substring(password: &str, min_entry_length: usize) -> bool {
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
+    let Some(bl) = guard.as_ref() else {
+        return false;
+    };
+    let candidate = normalize_password(password);
+    bl.iter()
+        .filter(|entry| entry.len() >= min_entry_length && entry.len() <= candidate.len())
+        .any(|entry| candidate.contains(entry.as_str()))
+}
+
+/// Returns the smallest edit distance from `password` to any blacklist
+/// entry, if it is at most `max`.
+///
+/// Useful to warn on near-misses such as `"passw0rd"` (distance 1 from
+/// `"password"`). Comparison is case-insensitive, like `is_blacklisted`.
+/// Returns `None` if no entry is within `max` edits or the blacklist is
+/// not initialized.
+///
+/// # Performance
+///
+/// Scans the whole set, computing an O(n·m) edit distance per entry for a
+/// password of length n and an entry of length m. Entries whose length differs
+/// by more than `max` are skipped, rows stop early once every distance
+/// exceeds the bound, and the scan stops at the first exact match. Keep
+/// `max` small and avoid calling this on every keystroke for large lists.
+pub fn nearest_blacklist_distance(password: &str, max: usize) -> Option<usize> {
+    let guard = COMMON_PASSWORDS.read().unwrap_or_else(|e| e.into_inner());
+    let bl = guard.as_ref()?;
+    let candidate: Zeroizing<Vec<char>> =
+        Zeroizing::new(normalize_password(password).chars().collect());
+
+    let mut best: Option<usize> = None;
+    for entry in bl {
+        let bound = best.map_or(max, |b| b - 1);
+        let entry: Vec<char> = entry.chars().collect();
+        if let Some(distance) = bounded_levenshtein(&candidate, &entry, bound) {
+            if distance == 0 {
+                return Some(0);
+            }
+            best = Some(distance);
+        }
+    }
+    best
+}
+
+/// A set of passwords the blacklist section checks against.
+///
+/// Implement this to back the blacklist with a database, a Redis set or a
+/// remote service instead of the global in-memory list, then set it as
+/// `EvaluatorConfig::blacklist_source`. `contains` receives each candidate
+/// as it appears in the password (and its look-alike skeleton), so
+/// case-folding is up to the implementation.
+pub trait BlacklistSource: fmt::Debug + Send + Sync {
+    /// Returns `true` if `password` is blacklisted.
+    fn contains(&self, password: &str) -> bool;
+
+    /// Returns `true` if `password` contains a blacklisted entry of at
+    /// least `min_entry_length` bytes, for
+    /// `EvaluatorConfig::blacklist_substring_min_length`.
+    ///
+    /// The default returns `false`: sources that cannot enumerate their
+    /// entries skip substring matching rather than fall back to the
+    /// global list.
+    fn contains_substring(&self, _password: &str, _min_entry_length: usize) -> bool {
+        false
+    }
+}
+
+/// An asynchronous blacklist, such as a remote lookup service.
+///
+/// Used by `evaluate_password_strength_with_source` (`async` feature
+/// only). Implementations report transport failures as
+/// `BlacklistError::Source`.
+///
+/// The crate makes no network requests of its own: remote lookups happen
+/// only through a source passed to `evaluate_password_strength_with_source`,
+/// so the other entry points are always offline and need no flag to stay
+/// so in air-gapped environments.
+#[cfg(feature = "async")]
+pub trait AsyncBlacklistSource: Send + Sync {
+    /// Resolves to `true` if `password` is blacklisted.
+    fn contains(&self, password: &str)
+    -> impl Future<Output = Result<bool, BlacklistError>> + Send;
+}
+
+/// The global blacklist loaded with `init_blacklist` and friends.
+///
+/// Used when `EvaluatorConfig::blacklist_source` is `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GlobalBlacklist;
+
+impl BlacklistSource for GlobalBlacklist {
+    fn contains(&self, password: &str) -> bool {
+        is_blacklisted(password)
+    }
+
+    fn contains_substring(&self, password: &str, min_entry_length: usize) -> bool {
+        contains_blacklisted_substring(password, min_entry_length)
+    }
+}
+
 /// Resets the blacklist for testing purposes.
 #[cfg(test)]
 pub fn reset_blacklist_for_testing() {
-    let mut guard = COMMON_PASSWORDS.write().unwrap();
+    let mut guard = COMMON_PASSWORDS.write().unwrap_or_else(|e| e.into_inner());
     *guard = None;
+    clear_default_blacklist_path();
 }
 
 #[cfg(test)]
@@ -181,6 +963,23 @@ mod tests {
         assert_eq!(path, PathBuf::from("./assets/blacklist.txt"));
     }
 
+    #[test]
+    #[serial]
+    fn test_get_blacklist_path_uses_default_constant() {
+        clear_default_blacklist_path();
+        remove_env("PWD_BLACKLIST_PATH");
+
+        assert_eq!(get_blacklist_path(), PathBuf::from(DEFAULT_BLACKLIST_PATH));
+        assert_eq!(crate::DEFAULT_BLACKLIST_PATH, DEFAULT_BLACKLIST_PATH);
+    }
+
+    #[test]
+    fn test_default_blacklist_path_matches_docs() {
+        let documented = format!("default: `{}`", DEFAULT_BLACKLIST_PATH);
+        assert!(include_str!("../README.md").contains(&documented));
+        assert!(include_str!("lib.rs").contains(&documented));
+    }
+
     #[test]
     #[serial]
     fn test_get_blacklist_path_from_env() {
@@ -194,6 +993,162 @@ mod tests {
         remove_env("PWD_BLACKLIST_PATH");
     }
 
+    #[test]
+    #[serial]
+    fn test_set_default_blacklist_path_used_by_init() {
+        reset_blacklist_for_testing();
+        remove_env("PWD_BLACKLIST_PATH");
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "password\nletmein").expect("Failed to write");
+
+        set_default_blacklist_path(temp_file.path());
+        assert_eq!(get_blacklist_path(), temp_file.path());
+
+        let count = init_blacklist().expect("Should load from the programmatic path");
+        assert_eq!(count, 2);
+        assert!(is_blacklisted("letmein"));
+        assert!(std::env::var("PWD_BLACKLIST_PATH").is_err());
+
+        clear_default_blacklist_path();
+        assert_eq!(get_blacklist_path(), PathBuf::from(DEFAULT_BLACKLIST_PATH));
+    }
+
+    #[test]
+    #[serial]
+    fn test_default_blacklist_path_overrides_env() {
+        reset_blacklist_for_testing();
+        set_env("PWD_BLACKLIST_PATH", "/from/env.txt");
+
+        set_default_blacklist_path("/from/code.txt");
+        assert_eq!(get_blacklist_path(), PathBuf::from("/from/code.txt"));
+
+        clear_default_blacklist_path();
+        assert_eq!(get_blacklist_path(), PathBuf::from("/from/env.txt"));
+
+        remove_env("PWD_BLACKLIST_PATH");
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_skips_overlong_lines() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "password").expect("Failed to write");
+        writeln!(temp_file, "{}", "x".repeat(1_000_000)).expect("Failed to write");
+        writeln!(temp_file, "qwerty").expect("Failed to write");
+
+        let report = init_blacklist_from_path_with_options(
+            temp_file.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .expect("Should load");
+
+        assert_eq!(
+            report,
+            BlacklistLoadReport {
+                loaded: 2,
+                skipped_too_long: 1,
+                skipped_invalid_utf8: 0,
+                merged_duplicates: 0
+            }
+        );
+        assert!(is_blacklisted("password"));
+        assert!(
+            get_blacklist()
+                .unwrap()
+                .iter()
+                .all(|e| e.len() <= DEFAULT_MAX_ENTRY_LENGTH)
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    #[serial]
+    fn test_parallel_load_matches_sequential() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        for i in 0..200_000 {
+            // Duplicates, case variants, padding, blanks and overlong lines
+            let line = match i % 5 {
+                0 => format!("Pass{}", i / 2),
+                1 => format!("  pass{}  ", i / 2),
+                2 => String::new(),
+                3 => format!("entry{}", i),
+                _ => "y".repeat(DEFAULT_MAX_ENTRY_LENGTH + (i % 3)),
+            };
+            writeln!(temp_file, "{}", line).expect("Failed to write");
+        }
+        let content = std::fs::read_to_string(temp_file.path()).unwrap();
+
+        let (sequential, sequential_skipped, sequential_merged) =
+            build_set(content.lines(), DEFAULT_MAX_ENTRY_LENGTH);
+        let (parallel, parallel_skipped, parallel_merged) =
+            build_set_from_content(&content, DEFAULT_MAX_ENTRY_LENGTH);
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel_skipped, sequential_skipped);
+        assert_eq!(parallel_merged, sequential_merged);
+
+        let report = init_blacklist_from_path_with_options(
+            temp_file.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .expect("Should load");
+        assert_eq!(report.loaded, sequential.len());
+        assert_eq!(report.skipped_too_long, sequential_skipped);
+        assert!(is_blacklisted("PASS10"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_merges_canonical_duplicates() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        write!(
+            temp_file,
+            "\u{FEFF}password\nPassword\r\npassword\t\t\npass\u{0}word\nqwerty\n"
+        )
+        .expect("Failed to write");
+
+        let report = init_blacklist_from_path_with_options(
+            temp_file.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .expect("Should load");
+
+        assert_eq!(report.loaded, 2);
+        assert_eq!(report.merged_duplicates, 3);
+        let mut entries: Vec<_> = get_blacklist().unwrap().into_iter().collect();
+        entries.sort();
+        assert_eq!(entries, ["password", "qwerty"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_custom_max_entry_length() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "short\nmuchlongerentry").expect("Failed to write");
+
+        let options = BlacklistLoadOptions {
+            max_entry_length: 8,
+            ..Default::default()
+        };
+        let report =
+            init_blacklist_from_path_with_options(temp_file.path(), &options).expect("Should load");
+
+        assert_eq!(
+            report,
+            BlacklistLoadReport {
+                loaded: 1,
+                skipped_too_long: 1,
+                skipped_invalid_utf8: 0,
+                merged_duplicates: 0
+            }
+        );
+        assert!(is_blacklisted("short"));
+        assert!(!is_blacklisted("muchlongerentry"));
+    }
+
     #[test]
     #[serial]
     fn test_init_blacklist_file_not_found() {
@@ -282,4 +1237,458 @@ mod tests {
 
         remove_env("PWD_BLACKLIST_PATH");
     }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_from_reader() {
+        reset_blacklist_for_testing();
+        let content: &[u8] = b"Password\n  qwerty  \n\nletmein\n";
+
+        let result = init_blacklist_from_reader(content);
+        assert_eq!(result.unwrap(), 3);
+        assert!(is_blacklisted("password"));
+        assert!(is_blacklisted("qwerty"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_from_reader_empty() {
+        reset_blacklist_for_testing();
+        let content: &[u8] = b"  \n\n";
+
+        let result = init_blacklist_from_reader(content);
+        assert!(matches!(result, Err(BlacklistError::EmptyFile)));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_from_iter() {
+        reset_blacklist_for_testing();
+
+        let result = init_blacklist_from_iter(["admin", "ADMIN", "letmein", " "]);
+        assert_eq!(result.unwrap(), 2);
+        assert!(is_blacklisted("Admin"));
+        assert!(!is_blacklisted("notlisted"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_with_blacklist_counts_matching_entries() {
+        reset_blacklist_for_testing();
+        assert_eq!(with_blacklist(|bl| bl.len()), None);
+
+        init_blacklist_from_iter(["123456", "password", "111111", "qwerty"]).unwrap();
+        let numeric = with_blacklist(|bl| {
+            bl.iter()
+                .filter(|e| e.chars().all(|c| c.is_ascii_digit()))
+                .count()
+        });
+        assert_eq!(numeric, Some(2));
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_from_iter_empty() {
+        reset_blacklist_for_testing();
+
+        let result = init_blacklist_from_iter(Vec::<String>::new());
+        assert!(matches!(result, Err(BlacklistError::EmptyFile)));
+    }
+
+    #[test]
+    #[serial]
+    fn test_is_blacklisted_capitalization_variants() {
+        reset_blacklist_for_testing();
+        // Entries are case-folded on load too
+        init_blacklist_from_iter(["password", "LetMeIn"]).unwrap();
+
+        for password in [
+            "Password", "PASSWORD", "PassWord", "pAsSwOrD", "letmein", "LETMEIN",
+        ] {
+            assert!(is_blacklisted(password), "{password}");
+            assert!(is_blacklisted_fast(password), "{password}");
+        }
+        assert!(contains_blacklisted_substring("myPassWord1", 8));
+        assert_eq!(nearest_blacklist_distance("PASSW0RD", 1), Some(1));
+        assert!(!is_blacklisted("Pass Word"));
+        assert!(!is_blacklisted_fast("Pass Word"));
+
+        reset_blacklist_for_testing();
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    #[serial]
+    fn test_is_blacklisted_normalizes_entries_and_input() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        // Stored decomposed, queried composed (and vice versa)
+        writeln!(temp_file, "re\u{0301}sume\u{0301}").expect("Failed to write");
+
+        let path = temp_file.path().to_str().unwrap();
+        set_env("PWD_BLACKLIST_PATH", path);
+
+        let _ = init_blacklist();
+
+        assert!(is_blacklisted("r\u{00e9}sum\u{00e9}"));
+        assert!(is_blacklisted("re\u{0301}sume\u{0301}"));
+
+        remove_env("PWD_BLACKLIST_PATH");
+    }
+
+    #[test]
+    #[serial]
+    fn test_nearest_blacklist_distance() {
+        reset_blacklist_for_testing();
+        let _ = init_blacklist_from_iter(["password", "123456", "qwerty"]);
+
+        assert_eq!(nearest_blacklist_distance("password", 3), Some(0));
+        assert_eq!(nearest_blacklist_distance("PassW0rd", 3), Some(1));
+        assert_eq!(nearest_blacklist_distance("qwerty12", 3), Some(2));
+        assert_eq!(nearest_blacklist_distance("qwerty12", 1), None);
+        assert_eq!(nearest_blacklist_distance("Kx7#mQ2!vR9z", 3), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_nearest_blacklist_distance_uninitialized() {
+        reset_blacklist_for_testing();
+        assert_eq!(nearest_blacklist_distance("password", 3), None);
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_skips_invalid_utf8_lines() {
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        temp_file
+            .write_all(b"password\nbad\xff\xfeline\nqwerty\n\xc3\n123456\n")
+            .expect("Failed to write");
+
+        let report = init_blacklist_from_path_with_options(
+            temp_file.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(report.loaded, 3);
+        assert_eq!(report.skipped_invalid_utf8, 2);
+        assert!(is_blacklisted("password"));
+        assert!(is_blacklisted("qwerty"));
+        assert!(is_blacklisted("123456"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_blacklist_from_reader_skips_invalid_utf8_lines() {
+        reset_blacklist_for_testing();
+        let bytes: &[u8] = b"password\n\xffqwerty\nadmin\n";
+        assert_eq!(init_blacklist_from_reader(bytes).unwrap(), 2);
+        assert!(is_blacklisted("admin"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_reload_blacklist_replaces_list() {
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter(["password", "qwerty"]).unwrap();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "letmein\nhunter2").expect("Failed to write");
+
+        let report = reload_blacklist_from_path_with_options(
+            temp_file.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .expect("Should reload");
+        assert_eq!(report.loaded, 2);
+        assert!(is_blacklisted("letmein"));
+        assert!(!is_blacklisted("password"));
+
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_reload_blacklist_keeps_list_on_empty_or_small_file() {
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter(["password", "qwerty", "123456"]).unwrap();
+
+        let empty = NamedTempFile::new().expect("Failed to create temp file");
+        let result =
+            reload_blacklist_from_path_with_options(empty.path(), &BlacklistLoadOptions::default());
+        assert!(matches!(result, Err(BlacklistError::EmptyFile)));
+
+        let mut small = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(small, "letmein").expect("Failed to write");
+        let options = BlacklistLoadOptions {
+            min_reload_entries: 2,
+            ..Default::default()
+        };
+        let result = reload_blacklist_from_path_with_options(small.path(), &options);
+        assert!(matches!(
+            result,
+            Err(BlacklistError::SuspiciouslySmall {
+                loaded: 1,
+                minimum: 2
+            })
+        ));
+
+        // The previous list is still active
+        assert_eq!(with_blacklist(|bl| bl.len()), Some(3));
+        assert!(is_blacklisted("password"));
+        assert!(!is_blacklisted("letmein"));
+
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_reload_blacklist_rejects_truncated_file_by_default() {
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter((0..1000).map(|i| format!("password{i}"))).unwrap();
+
+        let mut truncated = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(truncated, "password0\npassword1").expect("Failed to write");
+        let result = reload_blacklist_from_path_with_options(
+            truncated.path(),
+            &BlacklistLoadOptions::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(BlacklistError::SuspiciouslySmall {
+                loaded: 2,
+                minimum: 500
+            })
+        ));
+        assert_eq!(with_blacklist(|bl| bl.len()), Some(1000));
+
+        // A list of comparable size replaces it
+        let mut updated = NamedTempFile::new().expect("Failed to create temp file");
+        for i in 0..600 {
+            writeln!(updated, "letmein{i}").expect("Failed to write");
+        }
+        let report = reload_blacklist_from_path_with_options(
+            updated.path(),
+            &BlacklistLoadOptions::default(),
+        )
+        .expect("Should reload");
+        assert_eq!(report.loaded, 600);
+        assert!(is_blacklisted("letmein0") && !is_blacklisted("password0"));
+
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_extend_blacklist_unions_lists() {
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter(["password", "qwerty"]).unwrap();
+
+        let mut supplement = NamedTempFile::new().unwrap();
+        writeln!(supplement, "Hunter2\nqwerty\nletmein").unwrap();
+        assert_eq!(extend_blacklist_from_path(supplement.path()).unwrap(), 4);
+        assert_eq!(
+            extend_blacklist_from_iter(["dragon", "PASSWORD"]).unwrap(),
+            5
+        );
+
+        for member in ["password", "qwerty", "hunter2", "letmein", "dragon"] {
+            assert!(is_blacklisted(member), "{} missing", member);
+        }
+        assert!(matches!(
+            extend_blacklist_from_iter([" "]),
+            Err(BlacklistError::EmptyFile)
+        ));
+
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_extend_blacklist_before_init_fails() {
+        reset_blacklist_for_testing();
+        let mut supplement = NamedTempFile::new().unwrap();
+        writeln!(supplement, "letmein").unwrap();
+        assert!(matches!(
+            extend_blacklist_from_iter(["admin"]),
+            Err(BlacklistError::NotLoaded)
+        ));
+        assert!(matches!(
+            extend_blacklist_from_path(supplement.path()),
+            Err(BlacklistError::NotLoaded)
+        ));
+        assert!(!is_blacklist_loaded());
+
+        // The base list still loads afterwards, and can then be extended
+        assert_eq!(init_blacklist_from_iter(["password", "qwerty"]).unwrap(), 2);
+        assert_eq!(extend_blacklist_from_iter(["admin"]).unwrap(), 3);
+        assert!(is_blacklisted("password") && is_blacklisted("admin"));
+        reset_blacklist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_concurrent_init_reads_source_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, Barrier};
+
+        /// Reader that counts how many times a source was actually read.
+        struct CountingReader {
+            inner: &'static [u8],
+            reads: Arc<AtomicUsize>,
+            counted: bool,
+        }
+
+        impl std::io::Read for CountingReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if !self.counted {
+                    self.counted = true;
+                    self.reads.fetch_add(1, Ordering::SeqCst);
+                    // Widen the race window
+                    std::thread::sleep(std::time::Duration::from_millis(20));
+                }
+                self.inner.read(buf)
+            }
+        }
+
+        reset_blacklist_for_testing();
+        let reads = Arc::new(AtomicUsize::new(0));
+        let barrier = Arc::new(Barrier::new(8));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let reads = Arc::clone(&reads);
+                let barrier = Arc::clone(&barrier);
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    init_blacklist_from_reader(CountingReader {
+                        inner: b"password\nqwerty\n",
+                        reads,
+                        counted: false,
+                    })
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().unwrap(), 2);
+        }
+        assert_eq!(reads.load(Ordering::SeqCst), 1);
+
+        reset_blacklist_for_testing();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    #[serial]
+    async fn test_blocking_init_in_runtime_warns() {
+        #[cfg(feature = "tracing")]
+        {
+            use std::sync::{Arc, Mutex};
+            use tracing::field::{Field, Visit};
+            use tracing::span::{Attributes, Id, Record};
+
+            type Events = Arc<Mutex<Vec<(tracing::Level, String)>>>;
+
+            struct MessageVisitor<'a>(&'a mut String);
+
+            impl Visit for MessageVisitor<'_> {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "message" {
+                        *self.0 = format!("{:?}", value);
+                    }
+                }
+            }
+
+            struct CaptureSubscriber {
+                events: Events,
+            }
+
+            impl tracing::Subscriber for CaptureSubscriber {
+                fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                    true
+                }
+
+                fn new_span(&self, _: &Attributes<'_>) -> Id {
+                    Id::from_u64(1)
+                }
+
+                fn record(&self, _: &Id, _: &Record<'_>) {}
+                fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+                fn event(&self, event: &tracing::Event<'_>) {
+                    let mut message = String::new();
+                    event.record(&mut MessageVisitor(&mut message));
+                    self.events
+                        .lock()
+                        .unwrap()
+                        .push((*event.metadata().level(), message));
+                }
+
+                fn enter(&self, _: &Id) {}
+                fn exit(&self, _: &Id) {}
+            }
+
+            let events: Events = Arc::default();
+            let subscriber = CaptureSubscriber {
+                events: Arc::clone(&events),
+            };
+            assert!(tracing::subscriber::with_default(
+                subscriber,
+                warn_if_in_runtime
+            ));
+
+            let events = events.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].0, tracing::Level::WARN);
+            assert!(
+                events[0].1.contains("use init_blacklist_async"),
+                "{:?}",
+                events[0].1
+            );
+        }
+        assert!(warn_if_in_runtime());
+
+        reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "password\nqwerty").expect("Failed to write");
+        assert_eq!(
+            init_blacklist_from_path_async(temp_file.path())
+                .await
+                .unwrap(),
+            2
+        );
+        assert!(is_blacklisted("qwerty"));
+        reset_blacklist_for_testing();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_blocking_init_outside_runtime_does_not_warn() {
+        assert!(!warn_if_in_runtime());
+    }
+
+    #[test]
+    #[serial]
+    fn test_poisoned_lock_still_serves_lookups() {
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter(["password"]).unwrap();
+
+        let poisoner = std::thread::spawn(|| {
+            let _guard = COMMON_PASSWORDS.write().unwrap();
+            panic!("poison the blacklist lock");
+        });
+        assert!(poisoner.join().is_err());
+        assert!(COMMON_PASSWORDS.is_poisoned());
+
+        assert!(is_blacklisted("password"));
+        assert!(!is_blacklisted("Kx7#mQ2!vR9$"));
+        assert_eq!(get_blacklist().map(|bl| bl.len()), Some(1));
+
+        reset_blacklist_for_testing();
+        init_blacklist_from_iter(["qwerty"]).unwrap();
+        assert!(is_blacklisted("qwerty"));
+
+        COMMON_PASSWORDS.clear_poison();
+        reset_blacklist_for_testing();
+    }
 }
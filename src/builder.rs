@@ -0,0 +1,295 @@
+//! Password policy builder - assembles an `EvaluatorConfig` fluently.
+
+use std::sync::Arc;
+
+use pwd_types::{PasswordEvaluation, PasswordStrength};
+use secrecy::SecretString;
+
+use crate::blacklist::BlacklistSource;
+use crate::config::{EvaluatorConfig, ScoreBonuses};
+use crate::evaluator::{
+    evaluate_password_strength_with_config, is_acceptable_with_config, quick_reject,
+};
+use crate::keyboard::KeyboardLayout;
+use crate::locale::Locale;
+use crate::reason::{CharClass, ReasonCode};
+use crate::sections::{Section, SectionFn};
+
+/// A configured evaluator, built once and reused for every password.
+///
+/// # Example
+///
+/// ```rust
+/// use pwd_strength::{CharClass, PasswordPolicy, ReasonCode, Section};
+/// use secrecy::SecretString;
+///
+/// let policy = PasswordPolicy::builder()
+///     .min_length(12)
+///     .required_classes([CharClass::Lowercase, CharClass::Digit])
+///     .sections([Section::Length, Section::Variety, Section::Pattern])
+///     .penalty(ReasonCode::Sequential, 25)
+///     .build();
+///
+/// let password = SecretString::new("correct horse 42".to_string().into());
+/// assert!(policy.evaluate(&password).reasons.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PasswordPolicy {
+    config: EvaluatorConfig,
+}
+
+impl PasswordPolicy {
+    /// Starts a builder from the default configuration.
+    pub fn builder() -> PasswordPolicyBuilder {
+        PasswordPolicyBuilder::default()
+    }
+
+    /// Returns the configuration the policy evaluates with.
+    pub fn config(&self) -> &EvaluatorConfig {
+        &self.config
+    }
+
+    /// Evaluates `password` under this policy.
+    pub fn evaluate(&self, password: &SecretString) -> PasswordEvaluation {
+        evaluate_password_strength_with_config(
+            password,
+            &self.config,
+            #[cfg(feature = "async")]
+            None,
+        )
+    }
+
+    /// Returns `true` if `password` reaches at least `min_strength`.
+    pub fn is_acceptable(&self, password: &SecretString, min_strength: PasswordStrength) -> bool {
+        is_acceptable_with_config(password, &self.config, min_strength)
+    }
+
+    /// Returns `true` for obviously unacceptable passwords; see `quick_reject`.
+    pub fn quick_reject(&self, password: &SecretString) -> bool {
+        quick_reject(password, &self.config)
+    }
+}
+
+impl From<EvaluatorConfig> for PasswordPolicy {
+    fn from(config: EvaluatorConfig) -> Self {
+        Self { config }
+    }
+}
+
+/// Builder for `PasswordPolicy`; every setting not given keeps its
+/// `EvaluatorConfig::default()` value.
+#[derive(Debug, Clone, Default)]
+pub struct PasswordPolicyBuilder {
+    config: EvaluatorConfig,
+}
+
+impl PasswordPolicyBuilder {
+    /// Sets `EvaluatorConfig::min_length`.
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.config.min_length = min_length;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::max_length`.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.config.max_length = max_length;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::required_classes`.
+    pub fn required_classes(mut self, classes: impl IntoIterator<Item = CharClass>) -> Self {
+        self.config.required_classes = classes.into_iter().collect();
+        self
+    }
+
+    /// Sets `EvaluatorConfig::special_chars`.
+    pub fn special_chars(mut self, special_chars: impl Into<String>) -> Self {
+        self.config.special_chars = special_chars.into();
+        self
+    }
+
+    /// Sets `EvaluatorConfig::repeat_threshold`.
+    pub fn repeat_threshold(mut self, threshold: usize) -> Self {
+        self.config.repeat_threshold = threshold;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::sequential_threshold`.
+    pub fn sequential_threshold(mut self, threshold: usize) -> Self {
+        self.config.sequential_threshold = threshold;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::keyboard_layout`.
+    pub fn keyboard_layout(mut self, layout: KeyboardLayout) -> Self {
+        self.config.keyboard_layout = layout;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::locale` and the keyboard layout of the
+    /// locale, like `EvaluatorConfig::for_locale`.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.config.locale = locale;
+        self.config.keyboard_layout = locale.keyboard_layout();
+        self
+    }
+
+    /// Sets the penalty for `code`, keeping the other penalties.
+    pub fn penalty(mut self, code: ReasonCode, penalty: i64) -> Self {
+        self.config.penalties.insert(code, penalty);
+        self
+    }
+
+    /// Sets `EvaluatorConfig::min_score`.
+    pub fn min_score(mut self, min_score: i64) -> Self {
+        self.config.min_score = Some(min_score);
+        self
+    }
+
+    /// Checks the blacklist through `source` instead of the global list.
+    pub fn blacklist_source(mut self, source: impl BlacklistSource + 'static) -> Self {
+        self.config.blacklist_source = Some(Arc::new(source));
+        self
+    }
+
+    /// Sets the sections to run, in order.
+    pub fn sections(mut self, sections: impl IntoIterator<Item = Section>) -> Self {
+        self.config.sections = sections.into_iter().collect();
+        self
+    }
+
+    /// Appends a caller-supplied section, run after the built-in ones; see
+    /// `EvaluatorConfig::custom_sections`.
+    pub fn custom_section(mut self, name: &'static str, section: SectionFn) -> Self {
+        self.config.custom_sections.push((name, section));
+        self
+    }
+
+    /// Sets `EvaluatorConfig::bonuses`.
+    pub fn bonuses(mut self, bonuses: ScoreBonuses) -> Self {
+        self.config.bonuses = bonuses;
+        self
+    }
+
+    /// Scores passwords of at least `min_length` characters as
+    /// passphrases; see `EvaluatorConfig::passphrase_mode`.
+    pub fn passphrase_mode(mut self, min_length: usize) -> Self {
+        self.config.passphrase_mode = true;
+        self.config.passphrase_min_length = min_length;
+        self
+    }
+
+    /// Sets `EvaluatorConfig::fail_fast`.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.config.fail_fast = fail_fast;
+        self
+    }
+
+    /// Builds the policy.
+    pub fn build(self) -> PasswordPolicy {
+        PasswordPolicy {
+            config: self.config,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reason::Reason;
+    use crate::sections::{SectionContext, SectionResult};
+    use secrecy::ExposeSecret;
+    use std::collections::HashSet;
+
+    #[derive(Debug)]
+    struct SetSource(HashSet<&'static str>);
+
+    impl BlacklistSource for SetSource {
+        fn contains(&self, password: &str) -> bool {
+            self.0.contains(password.to_lowercase().as_str())
+        }
+    }
+
+    fn secret(password: &str) -> SecretString {
+        SecretString::new(password.to_string().into())
+    }
+
+    fn no_company_name(password: &SecretString, _ctx: &SectionContext) -> SectionResult {
+        let listed = password.expose_secret().to_lowercase().contains("acme");
+        Ok(listed.then(|| {
+            Reason::new(
+                ReasonCode::DeniedTerm,
+                "Password must not contain the company name",
+            )
+        }))
+    }
+
+    #[test]
+    fn test_builder_policy_evaluates_passwords() {
+        let policy = PasswordPolicy::builder()
+            .min_length(12)
+            .required_classes([CharClass::Lowercase, CharClass::Digit])
+            .sequential_threshold(3)
+            .penalty(ReasonCode::Sequential, 30)
+            .blacklist_source(SetSource(HashSet::from(["letmein2024"])))
+            .sections([
+                Section::Blacklist,
+                Section::Length,
+                Section::Variety,
+                Section::Pattern,
+            ])
+            .custom_section("company", no_company_name)
+            .bonuses(ScoreBonuses {
+                length: false,
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(policy.config().min_length, 12);
+        assert_eq!(
+            policy.config().section_names(),
+            ["blacklist", "length", "variety", "pattern", "company"]
+        );
+        assert!(
+            policy
+                .config()
+                .penalties
+                .contains_key(&ReasonCode::Blacklisted)
+        );
+
+        let listed = policy.evaluate(&secret("LetMeIn2024"));
+        assert!(listed.reasons.iter().any(|r| r.contains("known common")));
+        assert!(policy.quick_reject(&secret("LetMeIn2024")));
+
+        // Long enough with the required classes only
+        let compliant = policy.evaluate(&secret("correct horse 42"));
+        assert!(compliant.reasons.is_empty(), "{:?}", compliant.reasons);
+        assert!(policy.is_acceptable(&secret("correct horse 42"), PasswordStrength::MEDIUM));
+
+        let company = policy.evaluate(&secret("acme horse 42"));
+        assert_eq!(
+            company.reasons,
+            ["Password must not contain the company name"]
+        );
+        assert!(!policy.is_acceptable(&secret("acme horse 42"), PasswordStrength::STRONG));
+
+        let short = policy.evaluate(&secret("horse 42"));
+        assert_eq!(short.reasons, ["Password must be at least 12 characters"]);
+
+        let sequential = policy.evaluate(&secret("horse abc 427"));
+        let plain = policy.evaluate(&secret("horse axc 427"));
+        assert_eq!(
+            sequential.score.map(|s| s.value()),
+            plain.score.map(|s| s.value() - 30)
+        );
+    }
+
+    #[test]
+    fn test_policy_from_config() {
+        let config = EvaluatorConfig::default().with_min_score(70);
+        assert_eq!(PasswordPolicy::from(config.clone()).config(), &config);
+        assert_eq!(
+            PasswordPolicy::builder().build().config(),
+            &EvaluatorConfig::default()
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Evaluation cache - reuses results for recently seen passwords.
+//!
+//! Live typing re-submits the same intermediate input (backspace, then
+//! retype); the cache answers those repeats without running the sections.
+//! Entries are keyed by a salted SHA-256 of the password, never the
+//! plaintext.
+
+use std::collections::VecDeque;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::Mutex;
+
+use pwd_types::{PasswordEvaluation, PasswordScore};
+use secrecy::{ExposeSecret, SecretString};
+use sha2::{Digest, Sha256};
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::evaluate_password_strength_with_config;
+use crate::score::ScoreExt;
+
+/// Salted password hash identifying a cache entry.
+type Key = [u8; 32];
+
+/// Cached result; `PasswordEvaluation` is not `Clone`.
+struct Entry {
+    key: Key,
+    score: Option<i64>,
+    reasons: Vec<String>,
+}
+
+impl Entry {
+    fn evaluation(&self) -> PasswordEvaluation {
+        PasswordEvaluation {
+            score: self.score.map(PasswordScore::new_clamped),
+            reasons: self.reasons.clone(),
+        }
+    }
+}
+
+/// Evaluates with a fixed configuration, remembering the results for the
+/// `capacity` most recently used passwords (`cache` feature only).
+///
+/// The salt is random per cache, so the keys cannot be matched against
+/// precomputed hashes or across caches. A capacity of `0` disables
+/// caching.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let cache = EvaluationCache::new(EvaluatorConfig::default(), 32);
+///
+/// // On every keystroke:
+/// render(cache.evaluate(&password));
+/// ```
+pub struct EvaluationCache {
+    config: EvaluatorConfig,
+    capacity: usize,
+    salt: [u8; 16],
+    /// Most recently used first.
+    entries: Mutex<VecDeque<Entry>>,
+}
+
+impl EvaluationCache {
+    /// Creates an empty cache evaluating with `config`.
+    pub fn new(config: EvaluatorConfig, capacity: usize) -> Self {
+        let state = RandomState::new();
+        let mut salt = [0u8; 16];
+        salt[..8].copy_from_slice(&state.hash_one(0u8).to_le_bytes());
+        salt[8..].copy_from_slice(&state.hash_one(1u8).to_le_bytes());
+        Self {
+            config,
+            capacity,
+            salt,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the configuration the cache evaluates with.
+    pub fn config(&self) -> &EvaluatorConfig {
+        &self.config
+    }
+
+    /// Returns the maximum number of cached results.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if a result for `password` is cached.
+    pub fn contains(&self, password: &SecretString) -> bool {
+        let key = self.key(password);
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .any(|entry| entry.key == key)
+    }
+
+    /// Drops every cached result.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    /// Evaluates `password`, returning the cached result if it was seen
+    /// recently.
+    ///
+    /// The lock is not held while evaluating, so concurrent misses for the
+    /// same password may both run the sections.
+    pub fn evaluate(&self, password: &SecretString) -> PasswordEvaluation {
+        let key = self.key(password);
+        {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(index) = entries.iter().position(|entry| entry.key == key) {
+                let entry = entries.remove(index).expect("index is in bounds");
+                let evaluation = entry.evaluation();
+                entries.push_front(entry);
+                return evaluation;
+            }
+        }
+
+        let evaluation = evaluate_password_strength_with_config(
+            password,
+            &self.config,
+            #[cfg(feature = "async")]
+            None,
+        );
+        if self.capacity > 0 {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if !entries.iter().any(|entry| entry.key == key) {
+                entries.truncate(self.capacity - 1);
+                entries.push_front(Entry {
+                    key,
+                    score: evaluation.score.as_ref().map(|s| s.value()),
+                    reasons: evaluation.reasons.clone(),
+                });
+            }
+        }
+        evaluation
+    }
+
+    fn key(&self, password: &SecretString) -> Key {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt);
+        hasher.update(password.expose_secret().as_bytes());
+        hasher.finalize().into()
+    }
+}
+
+impl std::fmt::Debug for EvaluationCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvaluationCache")
+            .field("config", &self.config)
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{secret, setup_blacklist};
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_repeated_input_hits_cache() {
+        setup_blacklist();
+        let cache = EvaluationCache::new(EvaluatorConfig::default(), 4);
+        let pwd = secret("Pass1234word!");
+        assert!(!cache.contains(&pwd));
+
+        let first = cache.evaluate(&pwd);
+        assert!(cache.contains(&pwd));
+
+        // A blacklist change would alter a fresh evaluation, not a cached one
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["pass1234word!"]);
+        let second = cache.evaluate(&pwd);
+        assert_eq!(second.reasons, first.reasons);
+        assert_eq!(
+            second.score.map(|s| s.value()),
+            first.score.map(|s| s.value())
+        );
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(
+            cache
+                .evaluate(&pwd)
+                .reasons
+                .iter()
+                .any(|r| r.contains("known common"))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_cache_evicts_least_recently_used() {
+        setup_blacklist();
+        let cache = EvaluationCache::new(EvaluatorConfig::default(), 2);
+        let (a, b, c) = (
+            secret("Alpha#2024x"),
+            secret("Bravo#2024x"),
+            secret("Charlie#2024"),
+        );
+        cache.evaluate(&a);
+        cache.evaluate(&b);
+        cache.evaluate(&a);
+        cache.evaluate(&c);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&a) && cache.contains(&c));
+        assert!(!cache.contains(&b));
+
+        let disabled = EvaluationCache::new(EvaluatorConfig::default(), 0);
+        disabled.evaluate(&a);
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_keys_are_salted_hashes() {
+        let pwd = secret("Pass1234word!");
+        let (one, two) = (
+            EvaluationCache::new(EvaluatorConfig::default(), 1),
+            EvaluationCache::new(EvaluatorConfig::default(), 1),
+        );
+        assert_ne!(one.key(&pwd), two.key(&pwd));
+        assert_ne!(
+            one.key(&pwd),
+            <[u8; 32]>::from(Sha256::digest(b"Pass1234word!"))
+        );
+        assert!(!format!("{:?}", one).contains("Pass1234word!"));
+    }
+}
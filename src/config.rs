@@ -0,0 +1,583 @@
+//! Evaluator configuration
+//!
+//! Tunable limits and policy knobs used by the evaluator.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::blacklist::{BlacklistError, BlacklistSource};
+use crate::keyboard::KeyboardLayout;
+use crate::locale::Locale;
+#[cfg(feature = "metrics")]
+use crate::observer::EvaluationObserver;
+use crate::reason::{CharClass, ReasonCode};
+use crate::sections::{Section, SectionFn};
+
+#[cfg(feature = "regex")]
+use regex::Regex;
+#[cfg(any(feature = "regex", feature = "serde"))]
+use thiserror::Error;
+
+/// Default maximum password length (in bytes) accepted by the evaluator.
+pub const DEFAULT_MAX_LENGTH: usize = 1024;
+
+/// Default length, in characters, from which `EvaluatorConfig::passphrase_mode`
+/// scores a password as a passphrase.
+pub const DEFAULT_PASSPHRASE_MIN_LENGTH: usize = 20;
+
+/// Default minimum ratio of unique characters to total characters.
+pub const DEFAULT_MIN_UNIQUE_RATIO: f64 = 0.3;
+
+pub use crate::core::{
+    ALL_CHAR_CLASSES, DEFAULT_REPEAT_THRESHOLD, DEFAULT_SEQUENTIAL_THRESHOLD,
+    DEFAULT_SPECIAL_CHARS, DEFAULT_UNIQUE_CHAR_BONUSES, MIN_LENGTH, ScoreBonuses,
+};
+
+/// Default points subtracted per reason without a configured penalty.
+pub const DEFAULT_PENALTY: i64 = 10;
+
+/// Default penalty for blacklisted passwords, look-alikes of them,
+/// blacklisted words wrapped in, followed by or split by digits or
+/// symbols and denylisted terms.
+pub const DEFAULT_BLACKLIST_PENALTY: i64 = 40;
+
+/// Separators suggested for `EvaluatorConfig::blacklist_ignored_separators`.
+pub const DEFAULT_BLACKLIST_SEPARATORS: &str = "-_. ";
+
+/// What the blacklist section does when there is nothing to check
+/// against: no `EvaluatorConfig::blacklist_source` and no global blacklist
+/// loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum BlacklistMissingBehavior {
+    /// Pass silently, as if the password were not listed.
+    Ignore,
+    /// Pass with an advisory `ReasonCode::BlacklistNotLoaded` reason.
+    #[default]
+    Warn,
+    /// Fail the section. `try_evaluate_password_strength_with_config`
+    /// returns `BlacklistError::NotLoaded`; the other entry points report
+    /// the section error.
+    Error,
+}
+
+/// Configuration for password evaluation.
+///
+/// Use `EvaluatorConfig::default()` for the standard policy and override
+/// individual fields as needed.
+#[derive(Debug, Clone)]
+pub struct EvaluatorConfig {
+    /// Maximum password length in bytes.
+    ///
+    /// Longer inputs are rejected before any section runs, so oversized
+    /// payloads cannot trigger large allocations in the analysis. They are
+    /// reported as `NotEvaluated`, without a score.
+    pub max_length: usize,
+
+    /// Minimum ratio of unique characters to total characters.
+    ///
+    /// Passwords below this ratio (e.g. `"aaaaaaaaaaaa"`) are dominated by
+    /// repeated characters and are capped at WEAK regardless of length.
+    /// Set to `0.0` to disable the check.
+    pub min_unique_ratio: f64,
+
+    /// Number of identical consecutive characters flagged as repetitive.
+    ///
+    /// The default of 3 tolerates doubled letters (`"ll"`, `"ss"`) but not
+    /// triples; set to 2 to flag any doubling. Values below 2 are treated
+    /// as 2.
+    pub repeat_threshold: usize,
+
+    /// Minimum length of a run of consecutive characters (`"1234"`,
+    /// `"dcba"`) flagged as sequential.
+    ///
+    /// Set to 3 to also flag runs like `"abc"`. Values below 2 are treated
+    /// as 2.
+    pub sequential_threshold: usize,
+
+    /// Keyboard layout used to detect keyboard walks (`"asdf"` on QWERTY,
+    /// `"qsdf"` on AZERTY). Defaults to QWERTY.
+    pub keyboard_layout: KeyboardLayout,
+
+    /// Language of the users. The dictionary section also checks the word
+    /// list loaded for it with `init_locale_dictionary_from_path`.
+    ///
+    /// Set it with `for_locale` to pick the matching keyboard layout as
+    /// well. Defaults to `Locale::English`; see `Locale` for the fallback
+    /// of unsupported languages.
+    pub locale: Locale,
+
+    /// Minimum password length in bytes. Defaults to `MIN_LENGTH` (8).
+    pub min_length: usize,
+
+    /// Width of the band above the minimum length that earns an advisory.
+    ///
+    /// With a band of 4, passwords of 8 to 11 characters pass the length
+    /// check but get `ReasonCode::MarginalLength` ("consider 12+"), an
+    /// `Info` reason that costs no points. `0` (the default) disables the advisory.
+    pub length_advisory_band: usize,
+
+    /// Bonuses for the number of distinct characters, as
+    /// `(minimum unique characters, points)` pairs.
+    ///
+    /// The largest bonus whose threshold is met applies; bonuses do not
+    /// stack. Defaults to `DEFAULT_UNIQUE_CHAR_BONUSES` (+10 from 16
+    /// unique characters, +5 from 12). Leave empty to disable the bonus.
+    pub unique_char_bonuses: Vec<(usize, i64)>,
+
+    /// Score bonus components to grade, all enabled by default.
+    ///
+    /// Disable them all for a pass/fail policy where only the penalties
+    /// of failed checks lower the score; see `ScoreBonuses`.
+    pub bonuses: ScoreBonuses,
+
+    /// Score long passwords as passphrases.
+    ///
+    /// Passwords of at least `passphrase_min_length` characters skip the
+    /// `required_classes` check and get the full variety bonus, so
+    /// `"the quick brown fox jumps"` is rated on its length rather than
+    /// its lack of digits and symbols. Disabled by default.
+    pub passphrase_mode: bool,
+
+    /// Length in characters from which `passphrase_mode` applies.
+    /// Defaults to `DEFAULT_PASSPHRASE_MIN_LENGTH` (20).
+    pub passphrase_min_length: usize,
+
+    /// Character classes the variety section requires, in any order.
+    ///
+    /// Defaults to all four (`ALL_CHAR_CLASSES`). Remove a class to stop
+    /// requiring it; passwords containing it still earn its variety bonus.
+    pub required_classes: Vec<CharClass>,
+
+    /// Characters that count as special characters.
+    ///
+    /// Used by the variety section and the special-character bonuses.
+    /// Other symbols, whitespace included, still count toward length and
+    /// uniqueness but satisfy no class. Defaults to
+    /// `DEFAULT_SPECIAL_CHARS`.
+    pub special_chars: String,
+
+    /// Points subtracted per reason, keyed by reason code.
+    ///
+    /// Codes not in the map cost `DEFAULT_PENALTY`. Codes with
+    /// `Severity::Info` are never penalized. By default blacklist
+    /// hits cost `DEFAULT_BLACKLIST_PENALTY`, so a common password scores
+    /// far below one that merely misses a character type.
+    pub penalties: HashMap<ReasonCode, i64>,
+
+    /// Patterns the password must not match (`regex` feature only).
+    ///
+    /// Every pattern is matched against the whole password on each
+    /// evaluation, so cost grows with the number and size of patterns.
+    /// `regex` matches in linear time, but compiling a large or
+    /// user-supplied pattern can take noticeable time and memory: build the
+    /// config once and reuse it. Use `with_deny_patterns` to compile from
+    /// strings and report invalid patterns up front.
+    #[cfg(feature = "regex")]
+    pub deny_patterns: Vec<Regex>,
+
+    /// Minimum entry length for substring matches against the blacklist.
+    ///
+    /// When set, a password containing a blacklisted entry of at least this
+    /// many bytes (e.g. `"mypassword!"` with `"password"` listed) is
+    /// flagged. `None` (the default) only flags exact matches.
+    pub blacklist_substring_min_length: Option<usize>,
+
+    /// Also check the password against the blacklist with leading and
+    /// trailing non-alphabetic characters stripped.
+    ///
+    /// Catches the common "word + year" shape (`"admin2024"`, `"qwerty!!"`)
+    /// without a full substring scan. Disabled by default.
+    pub blacklist_strip_affixes: bool,
+
+    /// Separators removed from the password for a second blacklist lookup.
+    ///
+    /// With `Some(DEFAULT_BLACKLIST_SEPARATORS.to_string())`,
+    /// `"pass-word"` and `"p.a.s.s.w.o.r.d"` match a `"password"` entry.
+    /// `None` (the default) keeps lookups exact.
+    pub blacklist_ignored_separators: Option<String>,
+
+    /// What to do when no blacklist is loaded; see
+    /// `BlacklistMissingBehavior`. Defaults to `Warn`, so a missing
+    /// `init_blacklist` call shows up in the reasons without failing.
+    pub blacklist_missing: BlacklistMissingBehavior,
+
+    /// Sections to run, in order.
+    ///
+    /// Defaults to `Section::DEFAULT` (blacklist, denylist, length,
+    /// variety, pattern). Reorder to run cheap checks first, remove a section to
+    /// skip it, or add `Section::Dictionary` to flag single dictionary
+    /// words from the list loaded with `init_dictionary_from_path`.
+    pub sections: Vec<Section>,
+
+    /// Caller-supplied sections, run after `sections` in order.
+    ///
+    /// Each entry pairs a name, reported by `section_names` and in
+    /// timings, with the `SectionFn` to run. Its reasons are scored like
+    /// those of the built-in sections, with the penalty of their code.
+    /// Empty by default.
+    pub custom_sections: Vec<(&'static str, SectionFn)>,
+
+    /// Stop at the first section that fails.
+    ///
+    /// The evaluation then returns a zero score (WEAK) with that single
+    /// reason, skipping the remaining sections. Advisory (`Severity::Info`)
+    /// reasons do not stop the evaluation. Useful on high-throughput
+    /// endpoints that only need accept/reject.
+    pub fail_fast: bool,
+
+    /// Minimum raw score a password must reach.
+    ///
+    /// A password scoring below it gets a `ReasonCode::BelowMinScore`
+    /// reason and is capped at WEAK, whichever tier its score falls in.
+    /// `None` (the default) only applies the strength tiers.
+    pub min_score: Option<i64>,
+
+    /// Blacklist checked by the blacklist section.
+    ///
+    /// `None` (the default) uses the global list loaded with
+    /// `init_blacklist`. Substring matching
+    /// (`blacklist_substring_min_length`) goes through
+    /// `BlacklistSource::contains_substring`, which sources that cannot
+    /// enumerate their entries leave unimplemented, skipping it.
+    pub blacklist_source: Option<Arc<dyn BlacklistSource>>,
+
+    /// Blacklist file loaded by `EvaluatorConfig::init_blacklist`.
+    ///
+    /// `None` (the default) uses the path of `init_blacklist`: the one set
+    /// with `set_default_blacklist_path`, `PWD_BLACKLIST_PATH`, or
+    /// `DEFAULT_BLACKLIST_PATH`.
+    pub blacklist_path: Option<PathBuf>,
+
+    /// Callback computing a non-reversible fingerprint of the password,
+    /// e.g. an HMAC with a server key, for auditing attempted passwords
+    /// without storing them.
+    ///
+    /// Its output is returned in `DetailedEvaluation::fingerprint` and
+    /// passed to the observer. `None` (the default) disables it.
+    pub fingerprint: Option<Fingerprinter>,
+
+    /// Observer notified at the end of each evaluation (`metrics` feature
+    /// only).
+    ///
+    /// Receives the strength, score and reason codes, never the password.
+    /// `None` (the default) disables the hook.
+    #[cfg(feature = "metrics")]
+    pub observer: Option<Arc<dyn EvaluationObserver>>,
+}
+
+/// Fingerprint callback for `EvaluatorConfig::fingerprint`.
+///
+/// The callback receives the plaintext password and must return a value
+/// from which the password cannot be recovered.
+#[derive(Clone)]
+pub struct Fingerprinter(Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl Fingerprinter {
+    /// Wraps a fingerprint callback.
+    pub fn new(f: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Computes the fingerprint of `password`.
+    pub fn fingerprint(&self, password: &str) -> String {
+        (self.0)(password)
+    }
+}
+
+impl fmt::Debug for Fingerprinter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Fingerprinter(..)")
+    }
+}
+
+impl EvaluatorConfig {
+    /// Returns the default configuration adjusted for `locale`.
+    ///
+    /// Sets `locale`, selecting its dictionary word list, and the keyboard
+    /// layout used for walk detection, e.g. AZERTY for `Locale::French`.
+    pub fn for_locale(locale: Locale) -> Self {
+        Self {
+            locale,
+            keyboard_layout: locale.keyboard_layout(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the penalty for `code`.
+    pub fn penalty(&self, code: ReasonCode) -> i64 {
+        self.penalties
+            .get(&code)
+            .copied()
+            .unwrap_or(DEFAULT_PENALTY)
+    }
+
+    /// Sets the penalty for `code`.
+    pub fn with_penalty(mut self, code: ReasonCode, penalty: i64) -> Self {
+        self.penalties.insert(code, penalty);
+        self
+    }
+
+    /// Sets the minimum raw score; see `min_score`.
+    pub fn with_min_score(mut self, min_score: i64) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Loads the global blacklist from `blacklist_path`, or from the path
+    /// of `init_blacklist` if unset.
+    ///
+    /// Like `init_blacklist`, returns the current size without reloading
+    /// if the blacklist is already initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `BlacklistError` of `init_blacklist_from_path`.
+    pub fn init_blacklist(&self) -> Result<usize, BlacklistError> {
+        match &self.blacklist_path {
+            Some(path) => crate::blacklist::init_blacklist_from_path(path),
+            None => crate::blacklist::init_blacklist(),
+        }
+    }
+
+    /// Returns `true` if a password of `char_len` characters is scored as
+    /// a passphrase; see `passphrase_mode`.
+    pub(crate) fn is_passphrase(&self, char_len: usize) -> bool {
+        self.passphrase_mode && char_len >= self.passphrase_min_length
+    }
+
+    /// Returns `true` if the blacklist section has nothing to check
+    /// against: no `blacklist_source` and no global blacklist loaded.
+    pub(crate) fn blacklist_unavailable(&self) -> bool {
+        self.blacklist_source.is_none() && !crate::blacklist::is_blacklist_loaded()
+    }
+
+    /// Returns the names of the sections this configuration runs, in
+    /// execution order.
+    pub fn section_names(&self) -> Vec<&'static str> {
+        self.sections
+            .iter()
+            .map(|s| s.name())
+            .chain(self.custom_sections.iter().map(|&(name, _)| name))
+            .collect()
+    }
+
+    /// Compiles `patterns` and appends them to `deny_patterns`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidDenyPattern` for the first pattern that
+    /// fails to compile.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pwd_strength::EvaluatorConfig;
+    ///
+    /// let config = EvaluatorConfig::default()
+    ///     .with_deny_patterns(["2024", "(?i)^acme"])
+    ///     .expect("valid patterns");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn with_deny_patterns<I, S>(mut self, patterns: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for pattern in patterns {
+            self.deny_patterns.push(Regex::new(pattern.as_ref())?);
+        }
+        Ok(self)
+    }
+}
+
+impl Default for EvaluatorConfig {
+    fn default() -> Self {
+        Self {
+            max_length: DEFAULT_MAX_LENGTH,
+            min_unique_ratio: DEFAULT_MIN_UNIQUE_RATIO,
+            repeat_threshold: DEFAULT_REPEAT_THRESHOLD,
+            sequential_threshold: DEFAULT_SEQUENTIAL_THRESHOLD,
+            keyboard_layout: KeyboardLayout::default(),
+            locale: Locale::default(),
+            min_length: MIN_LENGTH,
+            length_advisory_band: 0,
+            unique_char_bonuses: DEFAULT_UNIQUE_CHAR_BONUSES.to_vec(),
+            bonuses: ScoreBonuses::default(),
+            passphrase_mode: false,
+            passphrase_min_length: DEFAULT_PASSPHRASE_MIN_LENGTH,
+            required_classes: ALL_CHAR_CLASSES.to_vec(),
+            special_chars: DEFAULT_SPECIAL_CHARS.to_string(),
+            penalties: HashMap::from([
+                (ReasonCode::Blacklisted, DEFAULT_BLACKLIST_PENALTY),
+                (ReasonCode::LookAlike, DEFAULT_BLACKLIST_PENALTY),
+                (ReasonCode::BlacklistedCore, DEFAULT_BLACKLIST_PENALTY),
+                (ReasonCode::TrivialSuffix, DEFAULT_BLACKLIST_PENALTY),
+                (ReasonCode::BlacklistedSeparated, DEFAULT_BLACKLIST_PENALTY),
+                (ReasonCode::DeniedTerm, DEFAULT_BLACKLIST_PENALTY),
+            ]),
+            #[cfg(feature = "regex")]
+            deny_patterns: Vec::new(),
+            blacklist_substring_min_length: None,
+            blacklist_strip_affixes: false,
+            blacklist_ignored_separators: None,
+            blacklist_missing: BlacklistMissingBehavior::default(),
+            sections: Section::DEFAULT.to_vec(),
+            custom_sections: Vec::new(),
+            fail_fast: false,
+            min_score: None,
+            blacklist_source: None,
+            blacklist_path: None,
+            fingerprint: None,
+            #[cfg(feature = "metrics")]
+            observer: None,
+        }
+    }
+}
+
+impl PartialEq for EvaluatorConfig {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "regex")]
+        {
+            let same_patterns = self.deny_patterns.len() == other.deny_patterns.len()
+                && self
+                    .deny_patterns
+                    .iter()
+                    .zip(&other.deny_patterns)
+                    .all(|(a, b)| a.as_str() == b.as_str());
+            if !same_patterns {
+                return false;
+            }
+        }
+        #[cfg(feature = "metrics")]
+        {
+            let same_observer = match (&self.observer, &other.observer) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            };
+            if !same_observer {
+                return false;
+            }
+        }
+        self.max_length == other.max_length
+            && self.min_unique_ratio == other.min_unique_ratio
+            && self.repeat_threshold == other.repeat_threshold
+            && self.sequential_threshold == other.sequential_threshold
+            && self.keyboard_layout == other.keyboard_layout
+            && self.locale == other.locale
+            && self.min_length == other.min_length
+            && self.length_advisory_band == other.length_advisory_band
+            && self.unique_char_bonuses == other.unique_char_bonuses
+            && self.bonuses == other.bonuses
+            && self.passphrase_mode == other.passphrase_mode
+            && self.passphrase_min_length == other.passphrase_min_length
+            && self.required_classes == other.required_classes
+            && self.special_chars == other.special_chars
+            && self.penalties == other.penalties
+            && self.blacklist_substring_min_length == other.blacklist_substring_min_length
+            && self.blacklist_strip_affixes == other.blacklist_strip_affixes
+            && self.blacklist_ignored_separators == other.blacklist_ignored_separators
+            && self.blacklist_missing == other.blacklist_missing
+            && self.sections == other.sections
+            && self.custom_sections.len() == other.custom_sections.len()
+            && self
+                .custom_sections
+                .iter()
+                .zip(&other.custom_sections)
+                .all(|(&(a, f), &(b, g))| a == b && std::ptr::fn_addr_eq(f, g))
+            && self.fail_fast == other.fail_fast
+            && self.min_score == other.min_score
+            && self.blacklist_path == other.blacklist_path
+            && match (&self.blacklist_source, &other.blacklist_source) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                _ => false,
+            }
+            && match (&self.fingerprint, &other.fingerprint) {
+                (None, None) => true,
+                (Some(a), Some(b)) => Arc::ptr_eq(&a.0, &b.0),
+                _ => false,
+            }
+    }
+}
+
+/// Errors raised while building an `EvaluatorConfig`.
+#[cfg(any(feature = "regex", feature = "serde"))]
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[cfg(feature = "regex")]
+    #[error("Invalid deny pattern: {0}")]
+    InvalidDenyPattern(#[from] regex::Error),
+    #[cfg(feature = "serde")]
+    #[error("Invalid TOML policy: {0}")]
+    InvalidToml(#[from] toml::de::Error),
+    #[cfg(feature = "serde")]
+    #[error("Invalid JSON policy: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_penalty_defaults_and_overrides() {
+        let config = EvaluatorConfig::default();
+        assert_eq!(
+            config.penalty(ReasonCode::Blacklisted),
+            DEFAULT_BLACKLIST_PENALTY
+        );
+        assert_eq!(config.penalty(ReasonCode::TooShort), DEFAULT_PENALTY);
+
+        let config = config.with_penalty(ReasonCode::TooShort, 25);
+        assert_eq!(config.penalty(ReasonCode::TooShort), 25);
+    }
+
+    #[test]
+    fn test_for_locale_selects_keyboard_layout() {
+        assert_eq!(
+            EvaluatorConfig::for_locale(Locale::French).keyboard_layout,
+            KeyboardLayout::Azerty
+        );
+        assert_eq!(
+            EvaluatorConfig::for_locale(Locale::French).locale,
+            Locale::French
+        );
+        assert_eq!(
+            EvaluatorConfig::for_locale(Locale::German).keyboard_layout,
+            KeyboardLayout::Qwertz
+        );
+        assert_eq!(
+            EvaluatorConfig::for_locale(Locale::English),
+            EvaluatorConfig::default()
+        );
+        // Unsupported languages keep the default layout
+        assert_eq!(
+            EvaluatorConfig::for_locale(Locale::from_tag("pt")).keyboard_layout,
+            KeyboardLayout::Qwerty
+        );
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_with_deny_patterns_compiles() {
+        let config = EvaluatorConfig::default()
+            .with_deny_patterns(["2024", "^acme"])
+            .unwrap();
+        assert_eq!(config.deny_patterns.len(), 2);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_with_deny_patterns_rejects_invalid() {
+        let result = EvaluatorConfig::default().with_deny_patterns(["valid", "(unclosed"]);
+        assert!(matches!(result, Err(ConfigError::InvalidDenyPattern(_))));
+    }
+}
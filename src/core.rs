@@ -0,0 +1,673 @@
+//! Core scoring - the deterministic checks and the score, without `std`.
+//!
+//! Everything here depends only on `alloc`, so it is available with
+//! `default-features = false` on targets without `std` (embedded, kernels).
+//! Checks take the password as a plain `&str`; copies made while scanning
+//! are zeroized. The `std` feature builds the evaluator on top of these
+//! functions, adding the blacklist, dictionary, `SecretString` handling and
+//! async support.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pwd_strength::core::{check_length, check_patterns, check_variety, score, CharStats};
+//! use pwd_strength::core::{PatternRules, ALL_CHAR_CLASSES, DEFAULT_SPECIAL_CHARS};
+//! use pwd_strength::core::{DEFAULT_UNIQUE_CHAR_BONUSES, MIN_LENGTH};
+//!
+//! let password = "Kx7#mQ2!vR9$";
+//! let codes: Vec<_> = [
+//!     check_length(password, MIN_LENGTH, 0),
+//!     check_variety(password, &ALL_CHAR_CLASSES, DEFAULT_SPECIAL_CHARS),
+//!     check_patterns(password, &PatternRules::default()),
+//! ]
+//! .into_iter()
+//! .flatten()
+//! .map(|reason| reason.code)
+//! .collect();
+//! let stats = CharStats::compute_with_specials(password, DEFAULT_SPECIAL_CHARS);
+//! assert!(score(&stats, &codes, &DEFAULT_UNIQUE_CHAR_BONUSES, |_| 10) >= 70);
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+pub use crate::keyboard::KeyboardLayout;
+pub use crate::reason::{CharClass, Reason, ReasonCode, Severity};
+pub use crate::stats::{CharClassCounts, CharStats};
+
+/// Default minimum password length, in bytes.
+pub const MIN_LENGTH: usize = 8;
+
+/// Every character class, in the order reasons list them.
+pub const ALL_CHAR_CLASSES: [CharClass; 4] = [
+    CharClass::Uppercase,
+    CharClass::Lowercase,
+    CharClass::Digit,
+    CharClass::Special,
+];
+
+/// Default number of identical consecutive characters flagged as repetitive.
+pub const DEFAULT_REPEAT_THRESHOLD: usize = 3;
+
+/// Default minimum length of a run of consecutive characters flagged as sequential.
+pub const DEFAULT_SEQUENTIAL_THRESHOLD: usize = 4;
+
+/// Default characters counted as special characters.
+pub const DEFAULT_SPECIAL_CHARS: &str = "!@#$%^&*()-_=+[]{};:,.<>?/";
+
+/// Default bonuses for unique characters: `(minimum unique characters, points)`.
+pub const DEFAULT_UNIQUE_CHAR_BONUSES: [(usize, i64); 2] = [(16, 10), (12, 5)];
+
+/// Highest possible score.
+pub const MAX_SCORE: i64 = 100;
+
+/// Highest score that still maps to WEAK.
+pub(crate) const WEAK_MAX_SCORE: i64 = 49;
+
+/// Minimum number of neighbouring keys flagged as a keyboard walk.
+const KEYBOARD_WALK_LENGTH: usize = 4;
+
+/// Minimum length, in characters, of a password flagged as a palindrome.
+const MIN_PALINDROME_LENGTH: usize = 4;
+
+/// Number of characters scanned between cancellation checks.
+const CANCEL_CHECK_INTERVAL: usize = 4096;
+
+/// Checks the password against `min_length` bytes.
+///
+/// Shorter passwords get `ReasonCode::TooShort`; with `advisory_band`
+/// above zero, passwords less than `advisory_band` bytes above the
+/// minimum get an advisory `ReasonCode::MarginalLength`.
+pub fn check_length(password: &str, min_length: usize, advisory_band: usize) -> Option<Reason> {
+    let code = length_code(password, min_length, advisory_band)?;
+    let message = match code {
+        ReasonCode::TooShort => format!("Password must be at least {} characters", min_length),
+        _ => format!(
+            "Password length is minimal; consider {}+",
+            min_length + advisory_band
+        ),
+    };
+    Some(Reason::new(code, message))
+}
+
+/// Like `check_length`, returning only the code.
+pub(crate) fn length_code(
+    password: &str,
+    min_length: usize,
+    advisory_band: usize,
+) -> Option<ReasonCode> {
+    let len = password.len();
+    if len < min_length {
+        Some(ReasonCode::TooShort)
+    } else if len < min_length + advisory_band {
+        Some(ReasonCode::MarginalLength)
+    } else {
+        None
+    }
+}
+
+/// Checks that the password has a character of each of the `required`
+/// classes, only the characters of `special_chars` counting as special.
+///
+/// The uppercase and lowercase requirements are waived when the password
+/// has letters and none of them are cased (Arabic, CJK, ...). The reason
+/// lists the missing classes in `Reason::missing_classes`, in the order
+/// of `ALL_CHAR_CLASSES`.
+pub fn check_variety(
+    password: &str,
+    required: &[CharClass],
+    special_chars: &str,
+) -> Option<Reason> {
+    let missing: Vec<_> = missing_classes(password, required, special_chars).collect();
+    if missing.is_empty() {
+        None
+    } else {
+        Some(Reason::missing(missing))
+    }
+}
+
+/// Like `check_variety`, returning only the code.
+#[cfg(feature = "std")]
+pub(crate) fn variety_code(
+    password: &str,
+    required: &[CharClass],
+    special_chars: &str,
+) -> Option<ReasonCode> {
+    missing_classes(password, required, special_chars)
+        .next()
+        .map(|_| ReasonCode::MissingCharacterTypes)
+}
+
+/// The `required` classes the password lacks, in the order of
+/// `ALL_CHAR_CLASSES`; see `check_variety`.
+fn missing_classes(
+    password: &str,
+    required: &[CharClass],
+    special_chars: &str,
+) -> impl Iterator<Item = CharClass> {
+    let has_upper = password.chars().any(|c| c.is_uppercase());
+    let has_lower = password.chars().any(|c| c.is_lowercase());
+    let has_digit = password.chars().any(|c| c.is_ascii_digit());
+    let has_special = password.chars().any(|c| special_chars.contains(c));
+    let caseless = !has_upper && !has_lower && password.chars().any(|c| c.is_alphabetic());
+
+    [
+        if !has_upper && !caseless {
+            Some(CharClass::Uppercase)
+        } else {
+            None
+        },
+        if !has_lower && !caseless {
+            Some(CharClass::Lowercase)
+        } else {
+            None
+        },
+        if !has_digit {
+            Some(CharClass::Digit)
+        } else {
+            None
+        },
+        if !has_special {
+            Some(CharClass::Special)
+        } else {
+            None
+        },
+    ]
+    .into_iter()
+    .flatten()
+    .filter(move |class| required.contains(class))
+}
+
+/// Thresholds used by `check_patterns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PatternRules {
+    /// Identical consecutive characters flagged as repetitive (at least 2).
+    pub repeat_threshold: usize,
+    /// Run length of consecutive code points flagged as sequential (at least 2).
+    pub sequential_threshold: usize,
+    /// Layout used to detect keyboard walks.
+    pub keyboard_layout: KeyboardLayout,
+}
+
+impl Default for PatternRules {
+    fn default() -> Self {
+        Self {
+            repeat_threshold: DEFAULT_REPEAT_THRESHOLD,
+            sequential_threshold: DEFAULT_SEQUENTIAL_THRESHOLD,
+            keyboard_layout: KeyboardLayout::default(),
+        }
+    }
+}
+
+/// Checks the password for phone numbers and dates, repeats, sequences of
+/// letters (ignoring case) or digits, keyboard walks of
+/// `KEYBOARD_WALK_LENGTH` keys and palindromes of at least
+/// `MIN_PALINDROME_LENGTH` characters, returning the first found.
+pub fn check_patterns(password: &str, rules: &PatternRules) -> Option<Reason> {
+    pattern_code_cancellable(password, rules, || false).map(pattern_reason)
+}
+
+/// Reason for a `code` found by `pattern_code_cancellable`, with its message.
+pub(crate) fn pattern_reason(code: ReasonCode) -> Reason {
+    let message = match code {
+        ReasonCode::Repetitive => "Password contains repetitive patterns",
+        ReasonCode::Sequential => "Password contains sequential patterns",
+        ReasonCode::KeyboardWalk => "Password contains a keyboard walk",
+        ReasonCode::Palindrome => "Password is a palindrome",
+        ReasonCode::PhoneNumber => "Password contains a phone number",
+        ReasonCode::Date => "Password contains a date",
+        _ => "Password contains a pattern",
+    };
+    Reason::new(code, message)
+}
+
+/// Like `check_patterns`, returning only the code, and `None` as soon as
+/// `is_cancelled` does, polled every `CANCEL_CHECK_INTERVAL` characters.
+pub(crate) fn pattern_code_cancellable(
+    password: &str,
+    rules: &PatternRules,
+    is_cancelled: impl Fn() -> bool,
+) -> Option<ReasonCode> {
+    if is_cancelled() {
+        return None;
+    }
+    let repeat_threshold = rules.repeat_threshold.max(2);
+    let sequential_threshold = rules.sequential_threshold.max(2);
+    let chars: Zeroizing<Vec<char>> = Zeroizing::new(password.chars().collect());
+    if chars.len() < repeat_threshold.min(sequential_threshold).min(3) {
+        return None;
+    }
+
+    // Check for phone numbers and birthdays (e.g., "Paul5551234567")
+    if let Some(code) = digit_shape_code(&chars) {
+        return Some(code);
+    }
+
+    // Check repeated chars (e.g., "aaa" with the default threshold)
+    let mut repeated_count = 1;
+    for i in 1..chars.len() {
+        if i.is_multiple_of(CANCEL_CHECK_INTERVAL) && is_cancelled() {
+            return None;
+        }
+        if chars[i] == chars[i - 1] {
+            repeated_count += 1;
+            if repeated_count >= repeat_threshold {
+                return Some(ReasonCode::Repetitive);
+            }
+        } else {
+            repeated_count = 1;
+        }
+    }
+
+    // Check for sequences (e.g., "1234" or "ABCdef" with the default threshold)
+    let mut run = 1;
+    for i in 1..chars.len() {
+        if i.is_multiple_of(CANCEL_CHECK_INTERVAL) && is_cancelled() {
+            return None;
+        }
+        let step = match (sequence_key(chars[i - 1]), sequence_key(chars[i])) {
+            (Some((prev_digit, prev)), Some((curr_digit, curr))) if prev_digit == curr_digit => {
+                curr - prev
+            }
+            _ => 0,
+        };
+        if step == 1 || step == -1 {
+            run += 1;
+            if run >= sequential_threshold {
+                return Some(ReasonCode::Sequential);
+            }
+        } else {
+            run = 1;
+        }
+    }
+
+    // Check for keyboard walks (e.g., "asdf" on QWERTY)
+    let layout = rules.keyboard_layout;
+    let mut walk = 1;
+    for i in 1..chars.len() {
+        if i.is_multiple_of(CANCEL_CHECK_INTERVAL) && is_cancelled() {
+            return None;
+        }
+        if layout.adjacent(chars[i - 1], chars[i]) {
+            walk += 1;
+            if walk >= KEYBOARD_WALK_LENGTH {
+                return Some(ReasonCode::KeyboardWalk);
+            }
+        } else {
+            walk = 1;
+        }
+    }
+
+    // Check for palindromes (e.g., "racecar")
+    if chars.len() >= MIN_PALINDROME_LENGTH && is_palindrome(&chars) {
+        return Some(ReasonCode::Palindrome);
+    }
+
+    None
+}
+
+/// Flags a run of digits that makes up most of the password and looks
+/// like a phone number (10 or 11 digits) or a date (6 or 8 digits in day,
+/// month and year order, any of DMY, MDY or YMD).
+fn digit_shape_code(chars: &[char]) -> Option<ReasonCode> {
+    let run = chars
+        .split(|c| !c.is_ascii_digit())
+        .max_by_key(|run| run.len())
+        .unwrap_or_default();
+    if run.len() * 2 <= chars.len() {
+        return None;
+    }
+    match run.len() {
+        10 | 11 => Some(ReasonCode::PhoneNumber),
+        6 | 8 if is_date(run) => Some(ReasonCode::Date),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the 6 or 8 `digits` read as a date in DMY, MDY or
+/// YMD order, with four-digit years between 1900 and 2099.
+fn is_date(digits: &[char]) -> bool {
+    let number = |range: core::ops::Range<usize>| {
+        digits[range]
+            .iter()
+            .fold(0, |n, c| n * 10 + (*c as u32 - '0' as u32))
+    };
+    let valid = |day: u32, month: u32, year: u32| {
+        (1..=31).contains(&day)
+            && (1..=12).contains(&month)
+            && (digits.len() == 6 || (1900..=2099).contains(&year))
+    };
+    let year_len = digits.len() - 4;
+    let (first, second) = (number(0..2), number(2..4));
+    let last_year = number(4..digits.len());
+    let (leading_year, month, day) = (
+        number(0..year_len),
+        number(year_len..year_len + 2),
+        number(year_len + 2..digits.len()),
+    );
+    valid(first, second, last_year)
+        || valid(second, first, last_year)
+        || valid(day, month, leading_year)
+}
+
+/// Position of `c` for sequence detection: `(true, value)` for ASCII
+/// digits, `(false, code point of the lowercase form)` for letters and
+/// `None` for anything else, so punctuation never forms a sequence and
+/// letters never continue a run of digits.
+fn sequence_key(c: char) -> Option<(bool, i64)> {
+    if c.is_ascii_digit() {
+        return Some((true, i64::from(c as u32 - '0' as u32)));
+    }
+    if !c.is_alphabetic() {
+        return None;
+    }
+    let mut lower = c.to_lowercase();
+    let folded = match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    };
+    Some((false, i64::from(folded as u32)))
+}
+
+/// Returns `true` if `chars` reads the same in both directions, ignoring case.
+fn is_palindrome(chars: &[char]) -> bool {
+    chars
+        .iter()
+        .zip(chars.iter().rev())
+        .take(chars.len() / 2)
+        .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+}
+
+/// Bonus components of `score_with_bonuses`, all enabled by default.
+///
+/// A disabled component is not graded and adds its full points, so with
+/// every component disabled the score depends on the penalties alone: a
+/// pass/fail measure rather than a meter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct ScoreBonuses {
+    /// Half a point per byte, up to 20.
+    pub length: bool,
+    /// 15 points per character class.
+    pub variety: bool,
+    /// 5 points above 12 bytes, 10 above 16.
+    pub extra_length: bool,
+    /// 5 points for two or more special characters.
+    pub multi_special: bool,
+    /// The best matching tier of `unique_char_bonuses`.
+    pub unique_chars: bool,
+}
+
+impl Default for ScoreBonuses {
+    fn default() -> Self {
+        Self {
+            length: true,
+            variety: true,
+            extra_length: true,
+            multi_special: true,
+            unique_chars: true,
+        }
+    }
+}
+
+/// Computes the score from the password statistics and the reason codes.
+///
+/// `score_with_bonuses` with every bonus enabled.
+pub fn score(
+    stats: &CharStats,
+    reasons: &[ReasonCode],
+    unique_char_bonuses: &[(usize, i64)],
+    penalty: impl Fn(ReasonCode) -> i64,
+) -> i64 {
+    score_with_bonuses(
+        stats,
+        reasons,
+        unique_char_bonuses,
+        &ScoreBonuses::default(),
+        penalty,
+    )
+}
+
+/// Computes the score from the password statistics and the reason codes.
+///
+/// The enabled `bonuses` for length, variety and unique characters (the
+/// best matching tier of `unique_char_bonuses`), minus `penalty` for each
+/// code that is not `Severity::Info`, capped at WEAK if
+/// `ReasonCode::LowUniqueness` is present. The result is always in
+/// `0..=MAX_SCORE`, and never decreases when only the length grows.
+pub fn score_with_bonuses(
+    stats: &CharStats,
+    reasons: &[ReasonCode],
+    unique_char_bonuses: &[(usize, i64)],
+    bonuses: &ScoreBonuses,
+    penalty: impl Fn(ReasonCode) -> i64,
+) -> i64 {
+    let pwd_len = stats.byte_len;
+    let mut score: i64 = 0;
+
+    // Length bonus: up to 20 points (0.5 per character, max 20)
+    score += if bonuses.length {
+        (pwd_len as f64 * 0.5).min(20.0) as i64
+    } else {
+        20
+    };
+
+    // Character variety: up to 60 points (15 per type)
+    let variety = if bonuses.variety {
+        stats.counts.variety()
+    } else {
+        ALL_CHAR_CLASSES.len()
+    };
+    score += (variety * 15) as i64;
+
+    // Extra length bonus: +5 if > 12, +10 if > 16
+    if !bonuses.extra_length || pwd_len > 16 {
+        score += 10;
+    } else if pwd_len > 12 {
+        score += 5;
+    }
+
+    // Multiple special chars bonus: +5 if 2+ special chars
+    if !bonuses.multi_special || stats.counts.special >= 2 {
+        score += 5;
+    }
+
+    // Entropy bonus: based on unique chars (best matching tier)
+    score += unique_char_bonuses
+        .iter()
+        .filter(|&&(threshold, _)| !bonuses.unique_chars || stats.unique >= threshold)
+        .map(|&(_, bonus)| bonus)
+        .max()
+        .unwrap_or(0);
+
+    // Penalties for reasons (weighted per reason code, advice is free)
+    score -= reasons
+        .iter()
+        .filter(|code| code.severity() != Severity::Info)
+        .map(|&code| penalty(code))
+        .sum::<i64>();
+
+    // Repetitive passwords are trivially guessable however long they are
+    if reasons.contains(&ReasonCode::LowUniqueness) {
+        score = score.min(WEAK_MAX_SCORE);
+    }
+
+    // Bonuses can add up past the maximum and penalties below zero
+    score.clamp(0, MAX_SCORE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_with_bonuses_disabled() {
+        let bonuses = DEFAULT_UNIQUE_CHAR_BONUSES;
+        let none = ScoreBonuses {
+            length: false,
+            variety: false,
+            extra_length: false,
+            multi_special: false,
+            unique_chars: false,
+        };
+        // Compliant but short: the meter grades it, pass/fail does not
+        let stats = CharStats::compute("Kx7#mQ2!");
+        let graded = score(&stats, &[], &bonuses, |_| 10);
+        assert!(graded < MAX_SCORE, "{graded}");
+        assert_eq!(
+            score_with_bonuses(&stats, &[], &bonuses, &none, |_| 10),
+            MAX_SCORE
+        );
+        let penalized =
+            score_with_bonuses(&stats, &[ReasonCode::Sequential], &bonuses, &none, |_| 30);
+        assert!(penalized < MAX_SCORE, "{penalized}");
+        let other = CharStats::compute("abcdefgh");
+        assert_eq!(
+            score_with_bonuses(&other, &[ReasonCode::Sequential], &bonuses, &none, |_| 30),
+            penalized
+        );
+        assert_eq!(
+            score_with_bonuses(&stats, &[], &bonuses, &ScoreBonuses::default(), |_| 10),
+            graded
+        );
+
+        // A single toggle grants only its own component
+        let flat_length = ScoreBonuses {
+            length: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            score_with_bonuses(&stats, &[], &bonuses, &flat_length, |_| 10),
+            graded + 16
+        );
+    }
+
+    #[test]
+    fn test_check_length() {
+        assert_eq!(
+            check_length("Short1!", MIN_LENGTH, 0).map(|r| r.code),
+            Some(ReasonCode::TooShort)
+        );
+        assert_eq!(check_length("12345678", MIN_LENGTH, 0), None);
+        assert_eq!(
+            check_length("12345678", MIN_LENGTH, 4).map(|r| r.code),
+            Some(ReasonCode::MarginalLength)
+        );
+        assert_eq!(
+            check_length("12345678", 12, 0).map(|r| r.message),
+            Some("Password must be at least 12 characters".into())
+        );
+    }
+
+    #[test]
+    fn test_check_variety() {
+        let reason =
+            check_variety("quietmoon42", &ALL_CHAR_CLASSES, DEFAULT_SPECIAL_CHARS).unwrap();
+        assert_eq!(
+            reason.missing_classes,
+            [CharClass::Uppercase, CharClass::Special]
+        );
+        assert_eq!(check_variety("Quiet~moon42", &ALL_CHAR_CLASSES, "~"), None);
+
+        // Only the required classes are checked
+        let required = [CharClass::Lowercase, CharClass::Digit];
+        assert_eq!(
+            check_variety("quietmoon42", &required, DEFAULT_SPECIAL_CHARS),
+            None
+        );
+        let reason = check_variety("quietmoon", &required, DEFAULT_SPECIAL_CHARS).unwrap();
+        assert_eq!(reason.missing_classes, [CharClass::Digit]);
+    }
+
+    #[test]
+    fn test_check_patterns() {
+        let rules = PatternRules::default();
+        assert_eq!(
+            check_patterns("Xk1234zz#", &rules).map(|r| r.code),
+            Some(ReasonCode::Sequential)
+        );
+        assert_eq!(check_patterns("Kx7#mQ2!vR9$", &rules), None);
+        let azerty = PatternRules {
+            keyboard_layout: KeyboardLayout::Azerty,
+            ..rules
+        };
+        assert_eq!(
+            check_patterns("7qsdf!", &azerty).map(|r| r.code),
+            Some(ReasonCode::KeyboardWalk)
+        );
+    }
+
+    #[test]
+    fn test_check_patterns_sequences_ignore_case_and_punctuation() {
+        let rules = PatternRules::default();
+        let sequential = |password| {
+            check_patterns(password, &rules).map(|r| r.code) == Some(ReasonCode::Sequential)
+        };
+        assert!(sequential("ABCD"));
+        assert!(sequential("xABCdefx"));
+        assert!(sequential("x9876x"));
+        // Each letter is repeated in the other case, so no letter follows its predecessor
+        assert!(!sequential("aAbBcC"));
+        // `:` is punctuation, leaving a run of three digits
+        assert!(!sequential("789:"));
+        assert!(!sequential("x789:;<"));
+        // Digits and letters are separate alphabets
+        assert!(!sequential("x89ab"));
+    }
+
+    #[test]
+    fn test_check_patterns_phone_numbers_and_dates() {
+        let rules = PatternRules::default();
+        let code = |password| check_patterns(password, &rules).map(|r| r.code);
+        assert_eq!(code("Paul5551234567"), Some(ReasonCode::PhoneNumber));
+        assert_eq!(code("bob01011990"), Some(ReasonCode::Date));
+        assert_eq!(code("Kx19900101"), Some(ReasonCode::Date));
+        assert_eq!(code("mia311299!"), Some(ReasonCode::Date));
+        // Not a valid date
+        assert_ne!(code("bob99999990"), Some(ReasonCode::Date));
+        // The run does not dominate the password
+        assert_ne!(code("Kx7#mQ2!vR9$01011990"), Some(ReasonCode::Date));
+        assert_ne!(
+            code("Kx7#mQ2!vR9$5551234567"),
+            Some(ReasonCode::PhoneNumber)
+        );
+    }
+
+    #[test]
+    fn test_score_penalties_and_cap() {
+        let stats = CharStats::compute_with_specials("Kx7#mQ2!vR9$", DEFAULT_SPECIAL_CHARS);
+        let clean = score(&stats, &[], &DEFAULT_UNIQUE_CHAR_BONUSES, |_| 10);
+        assert_eq!(
+            score(
+                &stats,
+                &[ReasonCode::Sequential],
+                &DEFAULT_UNIQUE_CHAR_BONUSES,
+                |_| 10
+            ),
+            clean - 10
+        );
+        assert_eq!(
+            score(
+                &stats,
+                &[ReasonCode::MarginalLength],
+                &DEFAULT_UNIQUE_CHAR_BONUSES,
+                |_| 10
+            ),
+            clean
+        );
+        assert!(
+            score(
+                &stats,
+                &[ReasonCode::LowUniqueness],
+                &DEFAULT_UNIQUE_CHAR_BONUSES,
+                |_| 0
+            ) <= WEAK_MAX_SCORE
+        );
+        assert_eq!(score(&stats, &[ReasonCode::Blacklisted], &[], |_| 1000), 0);
+    }
+}
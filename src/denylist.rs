@@ -0,0 +1,115 @@
+//! Denylist management module
+//!
+//! Holds a small curated list of terms that must never appear in a
+//! password, such as company or product names. Unlike the blacklist,
+//! which matches whole common passwords, any password containing a
+//! denylisted term is rejected.
+
+use std::sync::RwLock;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+static DENYLIST: RwLock<Option<Vec<String>>> = RwLock::new(None);
+
+#[derive(Error, Debug)]
+pub enum DenylistError {
+    #[error("Denylist is empty")]
+    EmptyList,
+}
+
+/// Initializes the denylist from in-memory terms.
+///
+/// Terms are trimmed and lowercased. If the denylist is already
+/// initialized, returns its size without reloading.
+///
+/// # Errors
+///
+/// Returns `DenylistError::EmptyList` if no non-blank term is provided.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pwd_strength::init_denylist_from_iter(["acmecorp", "roadrunner"])?;
+/// ```
+pub fn init_denylist_from_iter<I, S>(terms: I) -> Result<usize, DenylistError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let loaded = DENYLIST
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|d| d.len());
+    if let Some(count) = loaded {
+        return Ok(count);
+    }
+
+    let mut list: Vec<String> = terms
+        .into_iter()
+        .map(|t| t.as_ref().trim().to_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    list.sort();
+    list.dedup();
+    if list.is_empty() {
+        #[cfg(feature = "tracing")]
+        tracing::error!("Denylist initialization FAILED: no terms");
+        return Err(DenylistError::EmptyList);
+    }
+
+    let count = list.len();
+    *DENYLIST.write().unwrap_or_else(|e| e.into_inner()) = Some(list);
+
+    #[cfg(feature = "tracing")]
+    tracing::info!("Denylist initialized: {} terms", count);
+
+    Ok(count)
+}
+
+/// Checks if `password` contains a denylisted term (case-insensitive).
+///
+/// Returns `false` if the denylist is not initialized.
+pub fn contains_denied_term(password: &str) -> bool {
+    let guard = DENYLIST.read().unwrap_or_else(|e| e.into_inner());
+    let Some(list) = guard.as_ref() else {
+        return false;
+    };
+    let candidate = Zeroizing::new(password.to_lowercase());
+    list.iter().any(|term| candidate.contains(term.as_str()))
+}
+
+/// Resets the denylist for testing purposes.
+#[cfg(test)]
+pub fn reset_denylist_for_testing() {
+    *DENYLIST.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_init_denylist_from_iter() {
+        reset_denylist_for_testing();
+        let count = init_denylist_from_iter([" AcmeCorp ", "roadrunner", "acmecorp", ""]).unwrap();
+        assert_eq!(count, 2);
+        assert!(contains_denied_term("ACMECORP123"));
+        assert!(contains_denied_term("my-Roadrunner!"));
+        assert!(!contains_denied_term("acme-corp"));
+        reset_denylist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_denylist_rejects_empty() {
+        reset_denylist_for_testing();
+        assert!(matches!(
+            init_denylist_from_iter(["", "  "]),
+            Err(DenylistError::EmptyList)
+        ));
+        assert!(!contains_denied_term("anything"));
+    }
+}
@@ -0,0 +1,333 @@
+//! Dictionary management module
+//!
+//! Handles loading and querying the word list used by the dictionary
+//! section. The list is separate from the blacklist: it holds ordinary
+//! words rather than leaked passwords. Word lists of a `Locale` add to
+//! it for evaluations with that `EvaluatorConfig::locale`.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+use crate::locale::Locale;
+
+static DICTIONARY: RwLock<Option<HashSet<String>>> = RwLock::new(None);
+
+/// Word lists loaded with `init_locale_dictionary_from_path` and friends.
+static LOCALE_DICTIONARIES: RwLock<Vec<(Locale, HashSet<String>)>> = RwLock::new(Vec::new());
+
+#[derive(Error, Debug)]
+pub enum DictionaryError {
+    #[error("Dictionary file not found: {0}")]
+    FileNotFound(PathBuf),
+    #[error("Failed to read dictionary file: {0}")]
+    ReadError(#[from] std::io::Error),
+    #[error("Dictionary file is empty")]
+    EmptyFile,
+}
+
+/// Initializes the dictionary from a word list file, one word per line.
+///
+/// Words are trimmed and lowercased. If the dictionary is already
+/// initialized, returns its size without reloading.
+///
+/// # Errors
+///
+/// Returns error if:
+/// - File does not exist
+/// - File cannot be read
+/// - File is empty
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pwd_strength::init_dictionary_from_path("/usr/share/dict/words")?;
+/// ```
+pub fn init_dictionary_from_path<P: AsRef<std::path::Path>>(
+    path: P,
+) -> Result<usize, DictionaryError> {
+    if let Some(count) = loaded_count() {
+        return Ok(count);
+    }
+
+    let path = path.as_ref();
+
+    if !path.exists() {
+        #[cfg(feature = "tracing")]
+        tracing::error!("Dictionary initialization FAILED: FileNotFound {:?}", path);
+        return Err(DictionaryError::FileNotFound(path.to_path_buf()));
+    }
+
+    let content = std::fs::read_to_string(path)?;
+    let count = install(content.lines())?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!("Dictionary initialized: {} words from {:?}", count, path);
+
+    Ok(count)
+}
+
+/// Initializes the dictionary from in-memory words.
+///
+/// # Errors
+///
+/// Returns `DictionaryError::EmptyFile` if no non-blank word is provided.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pwd_strength::init_dictionary_from_iter(["elephant", "sunshine"])?;
+/// ```
+pub fn init_dictionary_from_iter<I, S>(words: I) -> Result<usize, DictionaryError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    if let Some(count) = loaded_count() {
+        return Ok(count);
+    }
+
+    let count = install(words)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!("Dictionary initialized: {} words from iterator", count);
+
+    Ok(count)
+}
+
+/// Initializes the word list of `locale` from a file, one word per line.
+///
+/// Passwords evaluated with `EvaluatorConfig::locale` set to `locale` are
+/// checked against these words in addition to the global dictionary, so
+/// a French deployment can load a French word list next to an English
+/// one. Locales without a list use the global dictionary alone. Like
+/// `init_dictionary_from_path`, returns the current size without
+/// reloading if the list of `locale` is already initialized.
+///
+/// # Errors
+///
+/// Same as `init_dictionary_from_path`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use pwd_strength::Locale;
+///
+/// pwd_strength::init_locale_dictionary_from_path(Locale::French, "/usr/share/dict/french")?;
+/// ```
+pub fn init_locale_dictionary_from_path<P: AsRef<std::path::Path>>(
+    locale: Locale,
+    path: P,
+) -> Result<usize, DictionaryError> {
+    if let Some(count) = locale_loaded_count(locale) {
+        return Ok(count);
+    }
+
+    let path = path.as_ref();
+    if !path.exists() {
+        return Err(DictionaryError::FileNotFound(path.to_path_buf()));
+    }
+
+    let content = std::fs::read_to_string(path)?;
+    install_locale(locale, content.lines())
+}
+
+/// Initializes the word list of `locale` from in-memory words; see
+/// `init_locale_dictionary_from_path`.
+///
+/// # Errors
+///
+/// Returns `DictionaryError::EmptyFile` if no non-blank word is provided.
+pub fn init_locale_dictionary_from_iter<I, S>(
+    locale: Locale,
+    words: I,
+) -> Result<usize, DictionaryError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    if let Some(count) = locale_loaded_count(locale) {
+        return Ok(count);
+    }
+    install_locale(locale, words)
+}
+
+/// Returns the number of loaded words, or `None` if not yet initialized.
+fn loaded_count() -> Option<usize> {
+    let guard = DICTIONARY.read().unwrap_or_else(|e| e.into_inner());
+    guard.as_ref().map(|s| s.len())
+}
+
+/// Returns the number of words loaded for `locale`, or `None` if its list
+/// is not yet initialized.
+fn locale_loaded_count(locale: Locale) -> Option<usize> {
+    let guard = LOCALE_DICTIONARIES
+        .read()
+        .unwrap_or_else(|e| e.into_inner());
+    guard
+        .iter()
+        .find(|(l, _)| *l == locale)
+        .map(|(_, s)| s.len())
+}
+
+/// Normalizes `words` and stores them as the active dictionary.
+fn install<I, S>(words: I) -> Result<usize, DictionaryError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let set = word_set(words)?;
+    let count = set.len();
+    *DICTIONARY.write().unwrap_or_else(|e| e.into_inner()) = Some(set);
+    Ok(count)
+}
+
+/// Normalizes `words` and stores them as the word list of `locale`.
+fn install_locale<I, S>(locale: Locale, words: I) -> Result<usize, DictionaryError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let set = word_set(words)?;
+    let count = set.len();
+    let mut guard = LOCALE_DICTIONARIES
+        .write()
+        .unwrap_or_else(|e| e.into_inner());
+    // Another thread may have loaded the list since the size check
+    if let Some((_, existing)) = guard.iter().find(|(l, _)| *l == locale) {
+        return Ok(existing.len());
+    }
+    guard.push((locale, set));
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        "Dictionary initialized: {} words for locale {}",
+        count,
+        locale.code()
+    );
+
+    Ok(count)
+}
+
+/// Trims and lowercases `words`, dropping blank ones.
+fn word_set<I, S>(words: I) -> Result<HashSet<String>, DictionaryError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let set: HashSet<String> = words
+        .into_iter()
+        .map(|w| w.as_ref().trim().to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if set.is_empty() {
+        #[cfg(feature = "tracing")]
+        tracing::error!("Dictionary initialization FAILED: no words");
+        return Err(DictionaryError::EmptyFile);
+    }
+    Ok(set)
+}
+
+/// Checks if `word` is in the dictionary (case-insensitive).
+///
+/// Returns `false` if the dictionary is not initialized.
+pub fn is_dictionary_word(word: &str) -> bool {
+    let guard = DICTIONARY.read().unwrap_or_else(|e| e.into_inner());
+    guard
+        .as_ref()
+        .map(|d| d.contains(Zeroizing::new(word.to_lowercase()).as_str()))
+        .unwrap_or(false)
+}
+
+/// Checks if `word` is in the global dictionary or the word list of
+/// `locale` (case-insensitive).
+///
+/// Returns `false` if neither is initialized.
+pub fn is_dictionary_word_for(word: &str, locale: Locale) -> bool {
+    if is_dictionary_word(word) {
+        return true;
+    }
+    let guard = LOCALE_DICTIONARIES
+        .read()
+        .unwrap_or_else(|e| e.into_inner());
+    guard
+        .iter()
+        .find(|(l, _)| *l == locale)
+        .is_some_and(|(_, d)| d.contains(Zeroizing::new(word.to_lowercase()).as_str()))
+}
+
+/// Resets the dictionary for testing purposes.
+#[cfg(test)]
+pub fn reset_dictionary_for_testing() {
+    *DICTIONARY.write().unwrap_or_else(|e| e.into_inner()) = None;
+    LOCALE_DICTIONARIES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    #[serial]
+    fn test_init_dictionary_from_path() {
+        reset_dictionary_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "Elephant\nsunshine\n\n").expect("Failed to write");
+
+        assert_eq!(init_dictionary_from_path(temp_file.path()).unwrap(), 2);
+        assert!(is_dictionary_word("elephant"));
+        assert!(is_dictionary_word("SUNSHINE"));
+        assert!(!is_dictionary_word("xqlethno"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_dictionary_file_not_found() {
+        reset_dictionary_for_testing();
+        let result = init_dictionary_from_path("/nonexistent/words.txt");
+        assert!(matches!(result, Err(DictionaryError::FileNotFound(_))));
+    }
+
+    #[test]
+    #[serial]
+    fn test_init_dictionary_from_iter_empty() {
+        reset_dictionary_for_testing();
+        assert!(matches!(
+            init_dictionary_from_iter(["", "  "]),
+            Err(DictionaryError::EmptyFile)
+        ));
+        assert!(!is_dictionary_word(""));
+    }
+
+    #[test]
+    #[serial]
+    fn test_locale_dictionary_adds_to_global() {
+        reset_dictionary_for_testing();
+        init_dictionary_from_iter(["elephant"]).unwrap();
+        let french = ["Soleil", "maison"];
+        assert_eq!(
+            init_locale_dictionary_from_iter(Locale::French, french).unwrap(),
+            2
+        );
+        // Already loaded: not replaced
+        assert_eq!(
+            init_locale_dictionary_from_iter(Locale::French, ["chat"]).unwrap(),
+            2
+        );
+
+        assert!(is_dictionary_word_for("SOLEIL", Locale::French));
+        assert!(is_dictionary_word_for("elephant", Locale::French));
+        // Other locales fall back to the global dictionary
+        assert!(!is_dictionary_word_for("soleil", Locale::German));
+        assert!(is_dictionary_word_for("elephant", Locale::German));
+        assert!(!is_dictionary_word("soleil"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Evaluation diff - what changed between two evaluations.
+//!
+//! Used to show feedback such as "now STRONG (+15)" when a user edits
+//! their password.
+
+use pwd_types::{PasswordEvaluation, PasswordStrength};
+
+/// Difference between an old and a new evaluation.
+#[derive(Debug, PartialEq)]
+pub struct EvaluationDiff {
+    /// New score minus old score, `None` if either evaluation has no score.
+    pub score_delta: Option<i64>,
+    /// Strength of the old evaluation.
+    pub old_strength: PasswordStrength,
+    /// Strength of the new evaluation.
+    pub new_strength: PasswordStrength,
+    /// Reasons present in the new evaluation but not the old one.
+    pub added_reasons: Vec<String>,
+    /// Reasons present in the old evaluation but not the new one.
+    pub removed_reasons: Vec<String>,
+}
+
+impl EvaluationDiff {
+    /// Returns `true` if the strength changed.
+    pub fn strength_changed(&self) -> bool {
+        self.old_strength != self.new_strength
+    }
+}
+
+/// Compares two evaluations of successive versions of a password.
+///
+/// Reasons are compared by message and keep the order of the evaluation
+/// they come from.
+///
+/// # Example
+///
+/// ```rust
+/// use pwd_strength::{diff_evaluations, evaluate_str};
+///
+/// let diff = diff_evaluations(&evaluate_str("password"), &evaluate_str("Kx7#mQ2!vR9$"));
+/// assert!(diff.score_delta.is_some_and(|delta| delta > 0));
+/// ```
+pub fn diff_evaluations(old: &PasswordEvaluation, new: &PasswordEvaluation) -> EvaluationDiff {
+    let score_delta = match (&old.score, &new.score) {
+        (Some(old), Some(new)) => Some(new.value() - old.value()),
+        _ => None,
+    };
+    EvaluationDiff {
+        score_delta,
+        old_strength: old.strength(),
+        new_strength: new.strength(),
+        added_reasons: missing_from(&new.reasons, &old.reasons),
+        removed_reasons: missing_from(&old.reasons, &new.reasons),
+    }
+}
+
+/// Returns the reasons of `reasons` that are not in `other`.
+fn missing_from(reasons: &[String], other: &[String]) -> Vec<String> {
+    reasons
+        .iter()
+        .filter(|r| !other.contains(r))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pwd_types::PasswordScore;
+
+    fn evaluation(score: Option<i64>, reasons: &[&str]) -> PasswordEvaluation {
+        PasswordEvaluation {
+            score: score.map(PasswordScore::new),
+            reasons: reasons.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff_weak_to_strong() {
+        let old = evaluation(
+            Some(35),
+            &[
+                "Missing: numbers, special characters",
+                "Password contains sequential patterns",
+            ],
+        );
+        let new = evaluation(Some(75), &["Password contains sequential patterns"]);
+
+        let diff = diff_evaluations(&old, &new);
+        assert_eq!(diff.score_delta, Some(40));
+        assert_eq!(diff.old_strength, PasswordStrength::WEAK);
+        assert_eq!(diff.new_strength, PasswordStrength::STRONG);
+        assert!(diff.strength_changed());
+        assert!(diff.added_reasons.is_empty());
+        assert_eq!(
+            diff.removed_reasons,
+            ["Missing: numbers, special characters"]
+        );
+    }
+
+    #[test]
+    fn test_diff_added_reason_and_unscored() {
+        let old = evaluation(Some(72), &[]);
+        let new = evaluation(Some(60), &["Password is a palindrome"]);
+        let diff = diff_evaluations(&old, &new);
+        assert_eq!(diff.score_delta, Some(-12));
+        assert_eq!(diff.added_reasons, ["Password is a palindrome"]);
+        assert!(diff.removed_reasons.is_empty());
+
+        let cancelled = evaluation(None, &["Evaluation cancelled"]);
+        let diff = diff_evaluations(&old, &cancelled);
+        assert_eq!(diff.score_delta, None);
+        assert_eq!(diff.new_strength, PasswordStrength::NotEvaluated);
+    }
+
+    #[test]
+    fn test_diff_identical_is_empty() {
+        let old = evaluation(Some(55), &["Password contains a keyboard walk"]);
+        let new = evaluation(Some(55), &["Password contains a keyboard walk"]);
+        let diff = diff_evaluations(&old, &new);
+        assert_eq!(diff.score_delta, Some(0));
+        assert!(!diff.strength_changed());
+        assert!(diff.added_reasons.is_empty() && diff.removed_reasons.is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! Password strength evaluator - main evaluation logic.
 
-use pwd_types::{PasswordEvaluation, PasswordScore};
+use pwd_types::{PasswordEvaluation, PasswordScore, PasswordStrength};
 use secrecy::{ExposeSecret, SecretString};
 
 #[cfg(feature = "async")]
@@ -9,12 +9,23 @@ use tokio::sync::mpsc;
 #[cfg(feature = "async")]
 use tokio_util::sync::CancellationToken;
 
-use crate::sections::{
-    blacklist_section, character_variety_section, length_section, pattern_analysis_section,
-};
+#[cfg(feature = "async")]
+use crate::blacklist::AsyncBlacklistSource;
+use crate::blacklist::BlacklistError;
+use crate::config::{BlacklistMissingBehavior, EvaluatorConfig};
+use crate::core::WEAK_MAX_SCORE;
+use crate::reason::{Reason, ReasonCode, Severity};
+use crate::requirements::{PolicySummary, RequirementStatus};
+use crate::score::ScoreExt;
+#[cfg(feature = "async")]
+use crate::sections::BLACKLISTED_MESSAGE;
+use crate::sections::{Section, SectionContext, SectionFn, length_section};
+use crate::stats::{CharClassCounts, CharStats};
 
 /// Evaluates password strength and returns a detailed evaluation.
 ///
+/// Uses the default `EvaluatorConfig`.
+///
 /// # Arguments
 /// * `password` - The password to evaluate
 /// * `token` - Optional cancellation token (async feature only)
@@ -25,35 +36,647 @@ pub fn evaluate_password_strength(
     password: &SecretString,
     #[cfg(feature = "async")] token: Option<CancellationToken>,
 ) -> PasswordEvaluation {
-    let mut reasons = Vec::new();
-    let mut is_cancelled = false;
-    let mut score: Option<i64> = None;
+    evaluate_password_strength_with_config(
+        password,
+        &EvaluatorConfig::default(),
+        #[cfg(feature = "async")]
+        token,
+    )
+}
+
+/// Evaluates a plain string slice with the default configuration.
+///
+/// Convenience wrapper for quick use and tests: the input is copied into a
+/// `SecretString`, but the caller's `&str` itself is not zeroized. Prefer
+/// `evaluate_password_strength` when the password is already secret.
+pub fn evaluate_str(password: &str) -> PasswordEvaluation {
+    evaluate_string(password.to_string())
+}
+
+/// Evaluates an owned string with the default configuration.
+///
+/// The string is moved into a `SecretString`, so it is zeroized when the
+/// evaluation finishes.
+pub fn evaluate_string(password: String) -> PasswordEvaluation {
+    let password = SecretString::new(password.into());
+    evaluate_password_strength(
+        &password,
+        #[cfg(feature = "async")]
+        None,
+    )
+}
+
+/// Evaluates a password supplied as raw bytes with the default
+/// configuration.
+///
+/// See `evaluate_bytes_with_config`.
+pub fn evaluate_bytes(password: &[u8]) -> PasswordEvaluation {
+    evaluate_bytes_with_config(password, &EvaluatorConfig::default())
+}
 
+/// Evaluates a password supplied as raw bytes, e.g. straight from a
+/// network buffer.
+///
+/// The bytes must be valid UTF-8: invalid input scores zero with the
+/// single reason "Password is not valid UTF-8" rather than being decoded
+/// lossily, as replacement characters would inflate the score of a
+/// password the user never typed. Oversized input is rejected before
+/// decoding. Valid input is copied into a `SecretString` and evaluated
+/// like `evaluate_password_strength_with_config`.
+pub fn evaluate_bytes_with_config(password: &[u8], config: &EvaluatorConfig) -> PasswordEvaluation {
+    if let Some(rejected) = reject_oversized(password.len(), config) {
+        return rejected;
+    }
+    let Ok(decoded) = std::str::from_utf8(password) else {
+        return PasswordEvaluation {
+            score: Some(PasswordScore::new_clamped(0)),
+            reasons: vec!["Password is not valid UTF-8".to_string()],
+        };
+    };
+    let password = SecretString::new(decoded.into());
+    evaluate_password_strength_with_config(
+        &password,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    )
+}
+
+/// Returns `true` if the password reaches at least `min_strength` under
+/// the default configuration.
+///
+/// A yes/no gate for signup forms that do not need the reasons.
+pub fn is_acceptable(password: &SecretString, min_strength: PasswordStrength) -> bool {
+    is_acceptable_with_config(password, &EvaluatorConfig::default(), min_strength)
+}
+
+/// Returns `true` if the password reaches at least `min_strength` under
+/// `config`.
+pub fn is_acceptable_with_config(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    min_strength: PasswordStrength,
+) -> bool {
+    let evaluation = evaluate_password_strength_with_config(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    );
+    strength_rank(&evaluation.strength()) >= strength_rank(&min_strength)
+}
+
+/// Returns `true` for passwords that are obviously unacceptable: empty,
+/// longer than `config.max_length`, shorter than `config.min_length` or
+/// an exact blacklist entry.
+///
+/// A fast screen for rate limiting and abuse detection: only the length
+/// and exact blacklist checks run (each only if its section is in
+/// `config.sections`), no reasons are built and no score is computed.
+/// Nothing is allocated unless the password has non-ASCII characters, is
+/// longer than `DEFAULT_MAX_LENGTH` bytes, or `config.blacklist_source`
+/// allocates. A `false` result does not make the password acceptable;
+/// use `is_acceptable_with_config` for that.
+pub fn quick_reject(password: &SecretString, config: &EvaluatorConfig) -> bool {
     let pwd = password.expose_secret();
-    let pwd_len = pwd.len();
+    if pwd.is_empty() || pwd.len() > config.max_length {
+        return true;
+    }
+    if config.sections.contains(&Section::Length) && pwd.len() < config.min_length {
+        return true;
+    }
+    if config.sections.contains(&Section::Blacklist) {
+        return match config.blacklist_source.as_deref() {
+            Some(source) => source.contains(pwd),
+            None => crate::blacklist::is_blacklisted_fast(pwd),
+        };
+    }
+    false
+}
 
-    // Orchestrator: execute sections in sequence
-    let sections: Vec<(&str, fn(&SecretString) -> Result<Option<String>, ()>)> = vec![
-        ("blacklist", blacklist_section),
-        ("length", length_section),
-        ("variety", character_variety_section),
-        ("pattern", pattern_analysis_section),
-    ];
+/// Returns the strength tier of `password` under `config`, the same as
+/// `evaluate_password_strength_with_config(..).strength()`.
+///
+/// For UIs that only render the tier: the built-in sections report only
+/// their reason codes, so no reason message is formatted (custom sections
+/// still build theirs) and the reason list of the evaluation is never
+/// assembled.
+/// Scoring is shared with the full evaluation, and passwords longer than
+/// `config.max_length` bytes are likewise `NotEvaluated`. Pairs well with
+/// `config.fail_fast`, which stops at the first failing section. Unlike
+/// the full evaluation, `config.observer` is not notified and there is no
+/// cancellation.
+pub fn strength_only(password: &SecretString, config: &EvaluatorConfig) -> PasswordStrength {
+    if password.expose_secret().is_empty() {
+        return strength_of(0);
+    }
+    if password.expose_secret().len() > config.max_length {
+        return PasswordStrength::NotEvaluated;
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    let normalized = crate::normalize::normalize_secret(password);
+    #[cfg(feature = "unicode-normalization")]
+    let password = normalized.as_ref().unwrap_or(password);
+
+    let ctx = SectionContext::new(config);
+    let mut codes = Vec::new();
+    let builtin = config
+        .sections
+        .iter()
+        .map(|section| section.code_function()(password, &ctx));
+    // Custom sections only return full reasons; keep their codes
+    let custom = config
+        .custom_sections
+        .iter()
+        .map(|&(_, section)| section(password, &ctx).map(|reason| reason.map(|r| r.code)));
+    for result in builtin.chain(custom) {
+        match result {
+            Ok(Some(code)) if config.fail_fast && code.severity() != Severity::Info => {
+                return strength_of(0);
+            }
+            Ok(Some(code)) => codes.push(code),
+            Ok(None) => {}
+            Err(()) => {
+                codes.push(ReasonCode::Error);
+                break;
+            }
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    if matches_deny_pattern(password, config) {
+        codes.push(ReasonCode::ForbiddenPattern);
+    }
+
+    let stats = CharStats::compute_with_specials(password.expose_secret(), &config.special_chars);
+    strength_of(score_codes(&stats, &mut codes, config).score)
+}
+
+/// Strength tier of `score`.
+pub(crate) fn strength_of(score: i64) -> PasswordStrength {
+    PasswordEvaluation {
+        score: Some(PasswordScore::new_clamped(score)),
+        reasons: Vec::new(),
+    }
+    .strength()
+}
+
+/// Position of `strength` in the WEAK..GOD ordering, as `PasswordStrength`
+/// does not implement `Ord`. `NotEvaluated` ranks below every tier.
+const fn strength_rank(strength: &PasswordStrength) -> u8 {
+    match strength {
+        PasswordStrength::NotEvaluated => 0,
+        PasswordStrength::WEAK => 1,
+        PasswordStrength::MEDIUM => 2,
+        PasswordStrength::STRONG => 3,
+        PasswordStrength::EPIC => 4,
+        PasswordStrength::GOD => 5,
+    }
+}
+
+/// Evaluates each candidate under `config` and returns the index and
+/// evaluation of the highest-scoring one, e.g. to pick among generated
+/// passwords.
+///
+/// Ties go to the lowest index; unscored evaluations rank below any
+/// score. Returns `None` if `candidates` is empty.
+pub fn strongest_of(
+    candidates: &[SecretString],
+    config: &EvaluatorConfig,
+) -> Option<(usize, PasswordEvaluation)> {
+    let mut best: Option<(usize, PasswordEvaluation)> = None;
+    for (index, candidate) in candidates.iter().enumerate() {
+        let evaluation = evaluate_password_strength_with_config(
+            candidate,
+            config,
+            #[cfg(feature = "async")]
+            None,
+        );
+        let value = evaluation.score.as_ref().map(|s| s.value());
+        if best
+            .as_ref()
+            .is_none_or(|(_, b)| value > b.score.as_ref().map(|s| s.value()))
+        {
+            best = Some((index, evaluation));
+        }
+    }
+    best
+}
+
+/// Evaluates password strength using a custom configuration.
+///
+/// # Arguments
+/// * `password` - The password to evaluate
+/// * `config` - Evaluation limits and policy
+/// * `token` - Optional cancellation token (async feature only)
+///
+/// # Returns
+/// A `PasswordEvaluation` containing score and reasons.
+/// Empty passwords are rejected immediately with a zero score and the
+/// single reason "Password is empty". Passwords longer than
+/// `config.max_length` are not evaluated: they have no score
+/// (`NotEvaluated`) and the single reason "Password too long". A cancelled
+/// evaluation has no score and the single reason "Evaluation cancelled";
+/// reasons found before cancellation are discarded.
+pub fn evaluate_password_strength_with_config(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+) -> PasswordEvaluation {
+    evaluate_password_strength_detailed(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        token,
+    )
+    .evaluation
+}
+
+/// Fallible `evaluate_password_strength_with_config`.
+///
+/// # Errors
+///
+/// Returns `BlacklistError::NotLoaded` if `config.blacklist_missing` is
+/// `BlacklistMissingBehavior::Error`, the blacklist section is enabled
+/// and there is no blacklist to check against.
+pub fn try_evaluate_password_strength_with_config(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+) -> Result<PasswordEvaluation, BlacklistError> {
+    if config.blacklist_missing == BlacklistMissingBehavior::Error
+        && config.sections.contains(&Section::Blacklist)
+        && config.blacklist_unavailable()
+    {
+        return Err(BlacklistError::NotLoaded);
+    }
+    Ok(evaluate_password_strength_with_config(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        token,
+    ))
+}
+
+/// Evaluation result together with the statistics gathered while scoring.
+#[derive(Debug)]
+pub struct DetailedEvaluation {
+    /// Score and reasons.
+    pub evaluation: PasswordEvaluation,
+    /// Characters per class. All zero if the password was rejected for
+    /// length or the evaluation was cancelled.
+    pub char_counts: CharClassCounts,
+    /// Every requirement checked by the sections that ran, passed or not,
+    /// in execution order. Empty if the password was rejected for length
+    /// or the evaluation was cancelled.
+    pub requirements: Vec<RequirementStatus>,
+    /// Minimum length, required classes and blacklist use of the policy.
+    pub policy: PolicySummary,
+    /// Output of `EvaluatorConfig::fingerprint`, `None` if not configured.
+    pub fingerprint: Option<String>,
+    /// How long the evaluation took (`timing` feature only).
+    #[cfg(feature = "timing")]
+    pub timing: EvaluationTiming,
+}
+
+/// Wall-clock timing of an evaluation (`timing` feature only).
+#[cfg(feature = "timing")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvaluationTiming {
+    /// Time spent in the whole pipeline, including the length guard,
+    /// normalization and scoring.
+    pub elapsed: std::time::Duration,
+    /// Time spent in each section that ran, by name, in execution order.
+    pub sections: Vec<(&'static str, std::time::Duration)>,
+}
+
+/// Evaluates password strength, also returning per-class character counts.
+///
+/// Behaves like `evaluate_password_strength_with_config`; see
+/// `DetailedEvaluation` for the additional data.
+///
+/// With the `tracing` feature the evaluation runs inside an `evaluate`
+/// span, and each section inside a `section` span recording its name,
+/// `outcome` (`passed`, `failed` or `error`) and `duration_us`. The
+/// password and reason messages are never recorded.
+///
+/// With the `timing` feature the result also carries the time spent in
+/// the evaluation and in each section, for users who do not run tracing.
+pub fn evaluate_password_strength_detailed(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+) -> DetailedEvaluation {
+    #[cfg(feature = "timing")]
+    let mut timing = EvaluationTiming::default();
+    let mut requirements = Vec::new();
+    let (evaluation, stats, fingerprint) = run_sections(
+        password,
+        config,
+        &sections_for(config, |_| true),
+        Vec::new(),
+        Some(&mut requirements),
+        #[cfg(feature = "async")]
+        token,
+        #[cfg(feature = "timing")]
+        Some(&mut timing),
+    );
+    let policy = crate::requirements::summary(config, &requirements);
+    if evaluation.score.is_none() {
+        requirements.clear();
+    }
+    DetailedEvaluation {
+        evaluation,
+        char_counts: stats.counts,
+        requirements,
+        policy,
+        fingerprint,
+        #[cfg(feature = "timing")]
+        timing,
+    }
+}
+
+/// Runs the evaluation, returning the statistics of the scored password.
+///
+/// The statistics are all zero if the password was rejected for length or
+/// the evaluation was cancelled.
+pub(crate) fn evaluate_with_stats(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+) -> (PasswordEvaluation, CharStats) {
+    let (evaluation, stats, _) = run_sections(
+        password,
+        config,
+        &sections_for(config, |_| true),
+        Vec::new(),
+        None,
+        #[cfg(feature = "async")]
+        token,
+        #[cfg(feature = "timing")]
+        None,
+    );
+    (evaluation, stats)
+}
+
+/// Runs the evaluation, returning the statistics and the reason codes of
+/// the scored password, without notifying `config.observer`.
+pub(crate) fn evaluate_with_codes(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+) -> (PasswordEvaluation, CharStats, Vec<ReasonCode>) {
+    score_sections(
+        password,
+        config,
+        &sections_for(config, |_| true),
+        Vec::new(),
+        None,
+        #[cfg(feature = "async")]
+        None,
+        #[cfg(feature = "timing")]
+        None,
+    )
+}
+
+/// Evaluates password strength without consulting the global blacklist.
+///
+/// Only the deterministic sections of `config.sections` (length, variety,
+/// pattern) run, plus `config.custom_sections`, the length guard and
+/// deny patterns, so the result depends solely on the password and
+/// `config` as long as the custom sections do not read global state; the
+/// blacklist and dictionary sections are skipped. Useful for
+/// reproducible tests of the scoring without `init_blacklist` or
+/// `serial_test`. Not a substitute for
+/// `evaluate_password_strength_with_config` in production: common
+/// passwords are not detected.
+pub fn evaluate_password_strength_pure(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+) -> PasswordEvaluation {
+    run_sections(
+        password,
+        config,
+        &sections_for(config, |s| !s.uses_global_state()),
+        Vec::new(),
+        None,
+        #[cfg(feature = "async")]
+        None,
+        #[cfg(feature = "timing")]
+        None,
+    )
+    .0
+}
+
+/// Evaluates password strength, checking the blacklist through an async
+/// source (e.g. a remote service).
+///
+/// `source` is awaited before the sections in `config.sections` run. If
+/// it reports the password, the local blacklist section is skipped and the password is
+/// scored as blacklisted; otherwise every section runs as in
+/// `evaluate_password_strength_with_config`, so look-alikes of entries in
+/// the configured blacklist are still caught.
+///
+/// Cancelling `token` while the lookup is pending drops the source's
+/// future, aborting the request, and returns the cancelled result.
+///
+/// # Errors
+///
+/// Returns the error of `source`, if any.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let evaluation = evaluate_password_strength_with_source(&password, &config, &remote, None).await?;
+/// ```
+#[cfg(feature = "async")]
+pub async fn evaluate_password_strength_with_source<S: AsyncBlacklistSource>(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    source: &S,
+    token: Option<CancellationToken>,
+) -> Result<PasswordEvaluation, BlacklistError> {
+    if let Some(rejected) = reject_invalid(password, config) {
+        return Ok(rejected);
+    }
+
+    let listed = tokio::select! {
+        listed = source.contains(password.expose_secret()) => listed?,
+        _ = cancellation(token.as_ref()) => return Ok(cancelled()),
+    };
+
+    let (sections, reasons) = if listed {
+        (
+            sections_for(config, |s| s != Section::Blacklist),
+            vec![Reason::new(ReasonCode::Blacklisted, BLACKLISTED_MESSAGE)],
+        )
+    } else {
+        (sections_for(config, |_| true), Vec::new())
+    };
+    Ok(run_sections(
+        password,
+        config,
+        &sections,
+        reasons,
+        None,
+        token,
+        #[cfg(feature = "timing")]
+        None,
+    )
+    .0)
+}
+
+/// Resolves when `token` is cancelled; never resolves without a token.
+#[cfg(feature = "async")]
+async fn cancellation(token: Option<&CancellationToken>) {
+    match token {
+        Some(t) => t.cancelled().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Runs `sections` in order and scores the password, starting from
+/// `reasons` already found by the caller. Also returns the fingerprint of
+/// the password if `config.fingerprint` is set.
+///
+/// With the `metrics` feature, `config.observer` is notified of the result.
+/// With the `timing` feature, durations are recorded into `timing` if given.
+fn run_sections(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    sections: &[(&'static str, SectionFn)],
+    reasons: Vec<Reason>,
+    requirements: Option<&mut Vec<RequirementStatus>>,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+    #[cfg(feature = "timing")] mut timing: Option<&mut EvaluationTiming>,
+) -> (PasswordEvaluation, CharStats, Option<String>) {
+    #[cfg(feature = "timing")]
+    let started = std::time::Instant::now();
+
+    let (evaluation, stats, _codes) = score_sections(
+        password,
+        config,
+        sections,
+        reasons,
+        requirements,
+        #[cfg(feature = "async")]
+        token,
+        #[cfg(feature = "timing")]
+        timing.as_mut().map(|t| &mut t.sections),
+    );
+
+    #[cfg(feature = "timing")]
+    if let Some(timing) = timing {
+        timing.elapsed = started.elapsed();
+    }
+
+    let fingerprint = config
+        .fingerprint
+        .as_ref()
+        .map(|f| f.fingerprint(password.expose_secret()));
+
+    #[cfg(feature = "metrics")]
+    if let Some(observer) = &config.observer {
+        observer.on_evaluation(&crate::observer::EvaluationOutcome {
+            strength: evaluation.strength(),
+            score: evaluation.score.as_ref().map(|s| s.value()),
+            reasons: &_codes,
+            fingerprint: fingerprint.as_deref(),
+        });
+    }
+
+    (evaluation, stats, fingerprint)
+}
+
+/// Body of `run_sections`, also returning the reason codes of the result.
+fn score_sections(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+    sections: &[(&'static str, SectionFn)],
+    mut reasons: Vec<Reason>,
+    mut requirements: Option<&mut Vec<RequirementStatus>>,
+    #[cfg(feature = "async")] token: Option<CancellationToken>,
+    #[cfg(feature = "timing")] mut durations: Option<&mut Vec<(&'static str, std::time::Duration)>>,
+) -> (PasswordEvaluation, CharStats, Vec<ReasonCode>) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::info_span!("evaluate", sections = sections.len()).entered();
+
+    // Length guard: reject empty and oversized input before any section runs
+    if let Some(rejected) = reject_invalid(password, config) {
+        return (rejected, CharStats::default(), Vec::new());
+    }
+    if config.fail_fast
+        && let Some(index) = reasons.iter().position(|r| r.severity() != Severity::Info)
+    {
+        let reason = reasons.swap_remove(index);
+        let code = reason.code;
+        return (failed_fast(reason), CharStats::default(), vec![code]);
+    }
+
+    // Normalize to NFC so equivalent compositions score identically
+    #[cfg(feature = "unicode-normalization")]
+    let normalized = crate::normalize::normalize_secret(password);
+    #[cfg(feature = "unicode-normalization")]
+    let password = normalized.as_ref().unwrap_or(password);
 
-    for (section_name, section_fn) in sections {
+    let ctx = SectionContext::new(config);
+    #[cfg(feature = "async")]
+    let ctx = ctx.with_token(token.as_ref());
+
+    // Orchestrator: execute sections in sequence
+    for &(_section_name, section_fn) in sections {
         // Check cancellation before each section (async only)
         #[cfg(feature = "async")]
         {
-            if let Some(ref t) = token {
-                if t.is_cancelled() {
-                    reasons.push("Evaluation cancelled".to_string());
-                    is_cancelled = true;
-                    break;
-                }
+            if let Some(ref t) = token
+                && t.is_cancelled()
+            {
+                return (cancelled(), CharStats::default(), Vec::new());
             }
         }
 
-        match section_fn(password) {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "section",
+            section = _section_name,
+            outcome = tracing::field::Empty,
+            duration_us = tracing::field::Empty,
+        )
+        .entered();
+        #[cfg(any(feature = "tracing", feature = "timing"))]
+        let started = std::time::Instant::now();
+
+        let result = section_fn(password, &ctx);
+
+        if let Some(requirements) = requirements.as_mut() {
+            requirements.extend(crate::requirements::statuses(
+                _section_name,
+                &result,
+                &config.required_classes,
+            ));
+        }
+
+        #[cfg(feature = "timing")]
+        if let Some(durations) = durations.as_mut() {
+            durations.push((_section_name, started.elapsed()));
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            let outcome = match &result {
+                Ok(Some(_)) => "failed",
+                Ok(None) => "passed",
+                Err(()) => "error",
+            };
+            span.record("outcome", outcome);
+            span.record("duration_us", started.elapsed().as_micros() as u64);
+        }
+
+        match result {
+            Ok(Some(reason)) if config.fail_fast && reason.severity() != Severity::Info => {
+                let code = reason.code;
+                return (failed_fast(reason), CharStats::default(), vec![code]);
+            }
             Ok(Some(reason)) => {
                 reasons.push(reason);
             }
@@ -62,82 +685,280 @@ pub fn evaluate_password_strength(
             }
             Err(()) => {
                 #[cfg(feature = "tracing")]
-                tracing::error!("Fatal error in password evaluation section: {}", section_name);
-                reasons.push("Error".to_string());
-                score = None;
+                tracing::error!(
+                    "Fatal error in password evaluation section: {}",
+                    _section_name
+                );
+                reasons.push(Reason::new(ReasonCode::Error, "Error"));
                 break;
             }
         }
     }
 
-    // Calculate strength and final score
-    if !is_cancelled {
-        // Length bonus: up to 20 points (0.5 per character, max 20)
-        let bonus = (pwd_len as f64 * 0.5).min(20.0) as i64;
-        let score_ref = score.get_or_insert(0);
-        *score_ref += bonus;
+    // A section may have returned early because it observed cancellation
+    #[cfg(feature = "async")]
+    if ctx.is_cancelled() {
+        return (cancelled(), CharStats::default(), Vec::new());
+    }
 
-        // Character variety: up to 60 points (15 per type)
-        let has_upper = pwd.chars().any(|c| c.is_uppercase());
-        let has_lower = pwd.chars().any(|c| c.is_lowercase());
-        let has_digit = pwd.chars().any(|c| c.is_ascii_digit());
-        let has_special = pwd.chars().any(|c| !c.is_alphanumeric());
-        let variety_count = [has_upper, has_lower, has_digit, has_special]
-            .iter()
-            .filter(|&&b| b)
-            .count();
-        let score_ref = score.get_or_insert(0);
-        *score_ref += (variety_count * 15) as i64;
-
-        // Extra length bonus: +5 if > 12, +10 if > 16
-        let score_ref = score.get_or_insert(0);
-        if pwd_len > 16 {
-            *score_ref += 10;
-        } else if pwd_len > 12 {
-            *score_ref += 5;
-        }
+    #[cfg(feature = "regex")]
+    if matches_deny_pattern(password, config) {
+        reasons.push(Reason::new(
+            ReasonCode::ForbiddenPattern,
+            "Password matches a forbidden pattern",
+        ));
+    }
 
-        // Multiple special chars bonus: +5 if 2+ special chars
-        let special_count = pwd.chars().filter(|c| !c.is_alphanumeric()).count();
-        if special_count >= 2 {
-            let score_ref = score.get_or_insert(0);
-            *score_ref += 5;
-        }
+    let stats = CharStats::compute_with_specials(password.expose_secret(), &config.special_chars);
+    let (evaluation, codes) = finish_with_codes(&stats, reasons, config);
+    (evaluation, stats, codes)
+}
 
-        // Entropy bonus: based on unique chars
-        let unique_chars: std::collections::HashSet<char> = pwd.chars().collect();
-        let unique_count = unique_chars.len();
-        let score_ref = score.get_or_insert(0);
-        if unique_count >= 16 {
-            *score_ref += 10;
-        } else if unique_count >= 12 {
-            *score_ref += 5;
-        }
+/// Default sections in execution order.
+pub(crate) const SECTIONS: [(&str, SectionFn); Section::DEFAULT.len()] = {
+    let mut sections: [(&str, SectionFn); Section::DEFAULT.len()] =
+        [("", length_section); Section::DEFAULT.len()];
+    let mut i = 0;
+    while i < Section::DEFAULT.len() {
+        sections[i] = (Section::DEFAULT[i].name(), Section::DEFAULT[i].function());
+        i += 1;
+    }
+    sections
+};
+
+/// Names of `SECTIONS`, in order.
+const SECTION_NAMES: [&str; SECTIONS.len()] = {
+    let mut names = [""; SECTIONS.len()];
+    let mut i = 0;
+    while i < SECTIONS.len() {
+        names[i] = SECTIONS[i].0;
+        i += 1;
+    }
+    names
+};
+
+/// Returns the names of the sections run by the default configuration,
+/// in execution order.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(
+///     pwd_strength::default_section_names(),
+///     ["blacklist", "denylist", "length", "variety", "pattern"]
+/// );
+/// ```
+pub fn default_section_names() -> &'static [&'static str] {
+    &SECTION_NAMES
+}
+
+/// Returns the sections `config` runs that satisfy `keep`, in execution
+/// order, followed by `config.custom_sections`.
+pub(crate) fn sections_for(
+    config: &EvaluatorConfig,
+    keep: impl Fn(Section) -> bool,
+) -> Vec<(&'static str, SectionFn)> {
+    config
+        .sections
+        .iter()
+        .filter(|&&s| keep(s))
+        .map(|&s| (s.name(), s.function()))
+        .chain(config.custom_sections.iter().copied())
+        .collect()
+}
+
+/// Returns the rejection for empty passwords or passwords longer than
+/// `config.max_length`.
+///
+/// An empty password scores zero with the single reason
+/// "Password is empty".
+pub(crate) fn reject_invalid(
+    password: &SecretString,
+    config: &EvaluatorConfig,
+) -> Option<PasswordEvaluation> {
+    if password.expose_secret().is_empty() {
+        return Some(PasswordEvaluation {
+            score: Some(PasswordScore::new_clamped(0)),
+            reasons: vec!["Password is empty".to_string()],
+        });
+    }
+    reject_oversized(password.expose_secret().len(), config)
+}
 
-        // Penalties for reasons (each reason subtracts points)
-        let score_ref = score.get_or_insert(0);
-        *score_ref -= (reasons.len() as i64) * 10;
+/// Returns the rejection for passwords of `len` bytes when it exceeds
+/// `config.max_length`.
+///
+/// Like a cancellation, the result has no score (`NotEvaluated`): the
+/// password was never assessed, so it is not reported as WEAK.
+fn reject_oversized(len: usize, config: &EvaluatorConfig) -> Option<PasswordEvaluation> {
+    if len <= config.max_length {
+        return None;
     }
+    Some(PasswordEvaluation {
+        score: None,
+        reasons: vec![format!(
+            "Password too long (maximum {} bytes)",
+            config.max_length
+        )],
+    })
+}
 
+/// Builds the result of an evaluation stopped by `config.fail_fast`: a
+/// zero score and the single failing reason.
+fn failed_fast(reason: Reason) -> PasswordEvaluation {
     PasswordEvaluation {
-        score: score.map(|s| PasswordScore::new(s)),
-        reasons,
+        score: Some(PasswordScore::new_clamped(0)),
+        reasons: vec![reason.message],
+    }
+}
+
+/// Builds the result of a cancelled evaluation.
+///
+/// Partial reasons are discarded: a cancelled result always has no score
+/// (`NotEvaluated`) and the single reason "Evaluation cancelled".
+#[cfg(feature = "async")]
+pub(crate) fn cancelled() -> PasswordEvaluation {
+    PasswordEvaluation {
+        score: None,
+        reasons: vec!["Evaluation cancelled".to_string()],
+    }
+}
+
+/// Calculates the final score from the password statistics and the collected reasons.
+#[cfg(feature = "async")]
+pub(crate) fn finish(
+    stats: &CharStats,
+    reasons: Vec<Reason>,
+    config: &EvaluatorConfig,
+) -> PasswordEvaluation {
+    finish_with_codes(stats, reasons, config).0
+}
+
+/// Like `finish`, also returning the codes of the final reasons.
+fn finish_with_codes(
+    stats: &CharStats,
+    mut reasons: Vec<Reason>,
+    config: &EvaluatorConfig,
+) -> (PasswordEvaluation, Vec<ReasonCode>) {
+    let mut codes: Vec<ReasonCode> = reasons.iter().map(|r| r.code).collect();
+    let scored = score_codes(stats, &mut codes, config);
+
+    if scored.repetitive {
+        reasons.push(Reason::new(
+            ReasonCode::LowUniqueness,
+            "Password is dominated by repeated characters",
+        ));
+    }
+    if let Some(min_score) = scored.below_min_score {
+        reasons.push(Reason::new(
+            ReasonCode::BelowMinScore,
+            format!(
+                "Password score {} is below the required minimum of {}",
+                scored.raw, min_score
+            ),
+        ));
+    }
+
+    let evaluation = PasswordEvaluation {
+        score: Some(PasswordScore::new_clamped(scored.score)),
+        reasons: reasons.into_iter().map(|r| r.message).collect(),
+    };
+    (evaluation, codes)
+}
+
+/// Outcome of `score_codes`.
+struct ScoredCodes {
+    /// Final score, in `0..=100`.
+    score: i64,
+    /// Score before the `min_score` cap.
+    raw: i64,
+    /// `true` if `ReasonCode::LowUniqueness` was added.
+    repetitive: bool,
+    /// `config.min_score` if the raw score fell below it and
+    /// `ReasonCode::BelowMinScore` was added.
+    below_min_score: Option<i64>,
+}
+
+/// Scores `codes` found by the sections, shared by the full evaluation
+/// and `strength_only`: adds `LowUniqueness` for passwords below
+/// `config.min_unique_ratio`, then applies the `config.min_score` cap,
+/// adding `BelowMinScore`.
+fn score_codes(
+    stats: &CharStats,
+    codes: &mut Vec<ReasonCode>,
+    config: &EvaluatorConfig,
+) -> ScoredCodes {
+    let repetitive = stats.char_len > 0
+        && (stats.unique as f64 / stats.char_len as f64) < config.min_unique_ratio;
+    if repetitive {
+        codes.push(ReasonCode::LowUniqueness);
+    }
+
+    let raw = score_from_components(stats, codes, config);
+    let below_min_score = config.min_score.filter(|&min_score| raw < min_score);
+    let score = if below_min_score.is_some() {
+        codes.push(ReasonCode::BelowMinScore);
+        raw.min(WEAK_MAX_SCORE)
+    } else {
+        raw
+    };
+    ScoredCodes {
+        score: score.clamp(0, 100),
+        raw,
+        repetitive,
+        below_min_score,
+    }
+}
+
+/// Returns `true` if `password` matches one of `config.deny_patterns`.
+#[cfg(feature = "regex")]
+fn matches_deny_pattern(password: &SecretString, config: &EvaluatorConfig) -> bool {
+    config
+        .deny_patterns
+        .iter()
+        .any(|re| re.is_match(password.expose_secret()))
+}
+
+/// Computes the score from the password statistics and the reason codes.
+///
+/// This is the pure scoring step of the evaluator:
+/// `core::score_with_bonuses` with `config.unique_char_bonuses`,
+/// `config.bonuses` (without grading variety for passphrases, see
+/// `config.passphrase_mode`) and `config.penalty`. The result is
+/// always in `0..=100`, and never decreases when only the length grows.
+pub fn score_from_components(
+    stats: &CharStats,
+    reasons: &[ReasonCode],
+    config: &EvaluatorConfig,
+) -> i64 {
+    let mut bonuses = config.bonuses;
+    if config.is_passphrase(stats.char_len) {
+        bonuses.variety = false;
     }
+    crate::core::score_with_bonuses(
+        stats,
+        reasons,
+        &config.unique_char_bonuses,
+        &bonuses,
+        |code| config.penalty(code),
+    )
 }
 
 /// Async version that sends evaluation result via channel.
+///
+/// Waits `DEFAULT_DEBOUNCE` before evaluating. For coalescing keystrokes
+/// see `LiveEvaluator`; for an event per section see
+/// `evaluate_password_strength_tx_events`.
 #[cfg(feature = "async")]
 pub async fn evaluate_password_strength_tx(
     password: &SecretString,
     token: CancellationToken,
     tx: mpsc::Sender<PasswordEvaluation>,
 ) {
-    use std::time::Duration;
-
     #[cfg(feature = "tracing")]
     tracing::info!("evaluation is about to start...");
 
-    tokio::time::sleep(Duration::from_millis(300)).await;
+    tokio::time::sleep(crate::live::DEFAULT_DEBOUNCE).await;
     let evaluation = evaluate_password_strength(password, Some(token));
 
     if let Err(_e) = tx.send(evaluation).await {
@@ -149,10 +970,9 @@ pub async fn evaluate_password_strength_tx(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use pwd_types::PasswordStrength;
+    use serial_test::serial;
     use std::io::Write;
     use tempfile::NamedTempFile;
-    use serial_test::serial;
 
     fn setup_with_tempfile(passwords: &[&str]) -> NamedTempFile {
         let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
@@ -255,8 +1075,10 @@ mod tests {
         let evaluation = evaluate_password_strength(&pwd);
 
         assert_eq!(evaluation.strength(), PasswordStrength::WEAK);
-        let has_blacklist_reason = evaluation.reasons.iter()
-            .any(|r| r.contains("10,000") || r.contains("common"));
+        let has_blacklist_reason = evaluation
+            .reasons
+            .iter()
+            .any(|r| r.contains("common password"));
         assert!(has_blacklist_reason);
 
         cleanup_blacklist();
@@ -275,7 +1097,8 @@ mod tests {
         let evaluation = evaluate_password_strength(&pwd);
 
         assert_eq!(evaluation.strength(), PasswordStrength::WEAK);
-        assert!(!evaluation.reasons.is_empty());
+        assert_eq!(evaluation.score.map(|s| s.value()), Some(0));
+        assert_eq!(evaluation.reasons, vec!["Password is empty".to_string()]);
 
         cleanup_blacklist();
     }
@@ -313,31 +1136,1409 @@ mod tests {
 
         cleanup_blacklist();
     }
-}
-
-#[cfg(all(test, feature = "async"))]
-mod async_tests {
-    use super::*;
-    use pwd_types::PasswordStrength;
-    use std::io::Write;
-    use tempfile::NamedTempFile;
-    use serial_test::serial;
 
-    fn setup_with_tempfile(passwords: &[&str]) -> NamedTempFile {
-        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
-        for pwd in passwords {
-            writeln!(temp_file, "{}", pwd).expect("Failed to write");
-        }
-        temp_file
-    }
+    #[test]
+    #[serial]
+    fn test_evaluate_oversized_password_short_circuits() {
+        setup_blacklist();
+        let pwd = SecretString::new("a".repeat(10 * 1024 * 1024).into());
 
-    fn set_env(key: &str, value: &str) {
-        unsafe { std::env::set_var(key, value); }
-    }
+        let start = std::time::Instant::now();
 
-    fn remove_env(key: &str) {
-        unsafe { std::env::remove_var(key); }
-    }
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength(&pwd);
+
+        assert!(start.elapsed() < std::time::Duration::from_millis(100));
+        assert!(evaluation.score.is_none());
+        assert_eq!(evaluation.strength(), PasswordStrength::NotEvaluated);
+        assert_eq!(evaluation.reasons.len(), 1);
+        assert!(evaluation.reasons[0].contains("too long"));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_custom_max_length() {
+        setup_blacklist();
+        let config = EvaluatorConfig {
+            max_length: 8,
+            ..Default::default()
+        };
+        let pwd = SecretString::new("MyPass123!".to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &config, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &config);
+
+        assert_eq!(
+            evaluation.reasons,
+            vec!["Password too long (maximum 8 bytes)".to_string()]
+        );
+        assert!(evaluation.score.is_none());
+        assert!(!is_acceptable_with_config(
+            &pwd,
+            &config,
+            PasswordStrength::WEAK
+        ));
+
+        // The limit counts bytes: four CJK characters take twelve
+        let cjk = SecretString::new("密码密码".to_string().into());
+        assert_eq!(strength_only(&cjk, &config), PasswordStrength::NotEvaluated);
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength_with_config(&cjk, &config, None);
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength_with_config(&cjk, &config);
+        assert_eq!(evaluation.reasons, ["Password too long (maximum 8 bytes)"]);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_detailed_char_counts() {
+        setup_blacklist();
+        let pwd = SecretString::new("Ab12!!cd".to_string().into());
+
+        #[cfg(feature = "async")]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default(), None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default());
+
+        assert_eq!(
+            detailed.char_counts,
+            CharClassCounts {
+                upper: 1,
+                lower: 3,
+                digit: 2,
+                special: 2,
+                other: 0
+            }
+        );
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength(&pwd);
+
+        assert_eq!(
+            detailed.evaluation.score.map(|s| s.value()),
+            evaluation.score.map(|s| s.value())
+        );
+        assert_eq!(detailed.evaluation.reasons, evaluation.reasons);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_detailed_requirements() {
+        use crate::reason::CharClass;
+        use crate::requirements::Requirement;
+
+        setup_blacklist();
+        // Long enough and not common, but no uppercase or symbol
+        let pwd = SecretString::new("quietmoon42".to_string().into());
+
+        #[cfg(feature = "async")]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default(), None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default());
+
+        let statuses: Vec<_> = detailed
+            .requirements
+            .iter()
+            .map(|s| (s.requirement, s.satisfied))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                (Requirement::Section("blacklist"), true),
+                (Requirement::Section("denylist"), true),
+                (Requirement::Section("length"), true),
+                (Requirement::CharClass(CharClass::Uppercase), false),
+                (Requirement::CharClass(CharClass::Lowercase), true),
+                (Requirement::CharClass(CharClass::Digit), true),
+                (Requirement::CharClass(CharClass::Special), false),
+                (Requirement::Section("pattern"), true),
+            ]
+        );
+
+        // Rejected input checks nothing
+        let empty = SecretString::new(String::new().into());
+
+        #[cfg(feature = "async")]
+        let detailed =
+            evaluate_password_strength_detailed(&empty, &EvaluatorConfig::default(), None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&empty, &EvaluatorConfig::default());
+
+        assert!(detailed.requirements.is_empty());
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_detailed_policy_summary() {
+        use crate::reason::CharClass;
+        use crate::requirements::PolicySummary;
+
+        let pwd = SecretString::new("quietmoon42".to_string().into());
+        let detailed_with = |config: &EvaluatorConfig| {
+            #[cfg(feature = "async")]
+            return evaluate_password_strength_detailed(&pwd, config, None);
+
+            #[cfg(not(feature = "async"))]
+            return evaluate_password_strength_detailed(&pwd, config);
+        };
+
+        setup_blacklist();
+        assert_eq!(
+            detailed_with(&EvaluatorConfig::default()).policy,
+            PolicySummary {
+                min_length: Some(8),
+                required_classes: vec![
+                    CharClass::Uppercase,
+                    CharClass::Lowercase,
+                    CharClass::Digit,
+                    CharClass::Special,
+                ],
+                blacklist_consulted: true,
+            }
+        );
+
+        // Without the variety and blacklist sections
+        let config = EvaluatorConfig {
+            sections: vec![Section::Length, Section::Pattern],
+            ..Default::default()
+        };
+        assert_eq!(
+            detailed_with(&config).policy,
+            PolicySummary {
+                min_length: Some(8),
+                required_classes: vec![],
+                blacklist_consulted: false
+            }
+        );
+
+        // The blacklist section runs, but no blacklist is loaded
+        crate::blacklist::reset_blacklist_for_testing();
+        let policy = detailed_with(&EvaluatorConfig::default()).policy;
+        assert!(!policy.blacklist_consulted);
+
+        // A custom source is always consulted
+        #[derive(Debug)]
+        struct NoSource;
+        impl crate::blacklist::BlacklistSource for NoSource {
+            fn contains(&self, _: &str) -> bool {
+                false
+            }
+        }
+        let config = EvaluatorConfig {
+            blacklist_source: Some(std::sync::Arc::new(NoSource)),
+            ..Default::default()
+        };
+        assert!(detailed_with(&config).policy.blacklist_consulted);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_single_repeated_char_is_weak() {
+        setup_blacklist();
+        let pwd = SecretString::new("aaaaaaaaaaaa".to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength(&pwd);
+
+        assert_eq!(evaluation.strength(), PasswordStrength::WEAK);
+        assert!(
+            evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("repeated characters"))
+        );
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_varied_password_not_flagged_as_repetitive() {
+        setup_blacklist();
+        let pwd = SecretString::new("Kx7#mQ2!vR9z".to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength(&pwd);
+
+        assert!(
+            !evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("repeated characters"))
+        );
+        assert_ne!(evaluation.strength(), PasswordStrength::WEAK);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_min_unique_ratio_configurable() {
+        setup_blacklist();
+        let pwd = SecretString::new("abababababab".to_string().into());
+        let strict = EvaluatorConfig {
+            min_unique_ratio: 0.3,
+            ..Default::default()
+        };
+        let disabled = EvaluatorConfig {
+            min_unique_ratio: 0.0,
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let (strict, disabled) = (
+            evaluate_password_strength_with_config(&pwd, &strict, None),
+            evaluate_password_strength_with_config(&pwd, &disabled, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (strict, disabled) = (
+            evaluate_password_strength_with_config(&pwd, &strict),
+            evaluate_password_strength_with_config(&pwd, &disabled),
+        );
+
+        assert!(
+            strict
+                .reasons
+                .iter()
+                .any(|r| r.contains("repeated characters"))
+        );
+        assert!(
+            !disabled
+                .reasons
+                .iter()
+                .any(|r| r.contains("repeated characters"))
+        );
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_blacklist_penalized_more_than_missing_class() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["Tr0ub4dor&3Xq"]);
+        let blacklisted = SecretString::new("Tr0ub4dor&3Xq".to_string().into());
+        let missing_special = SecretString::new("Tr0ub4dor3Xqz".to_string().into());
+        let score = |pwd: &SecretString, config: &EvaluatorConfig| {
+            #[cfg(feature = "async")]
+            let evaluation = evaluate_password_strength_with_config(pwd, config, None);
+
+            #[cfg(not(feature = "async"))]
+            let evaluation = evaluate_password_strength_with_config(pwd, config);
+
+            evaluation.score.unwrap().value()
+        };
+
+        let config = EvaluatorConfig::default();
+        let blacklisted_score = score(&blacklisted, &config);
+        let missing_score = score(&missing_special, &config);
+        assert!(blacklisted_score + 10 < missing_score);
+
+        let harsh = EvaluatorConfig::default().with_penalty(ReasonCode::Blacklisted, 100);
+        assert_eq!(
+            score(&blacklisted, &harsh),
+            (blacklisted_score - (100 - config.penalty(ReasonCode::Blacklisted))).max(0)
+        );
+        assert_eq!(score(&missing_special, &harsh), missing_score);
+
+        cleanup_blacklist();
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    #[serial]
+    fn test_evaluate_emits_span_per_section() {
+        use std::collections::HashMap;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+
+        type Spans = Arc<Mutex<Vec<(&'static str, HashMap<String, String>)>>>;
+
+        struct FieldsVisitor<'a>(&'a mut HashMap<String, String>);
+
+        impl Visit for FieldsVisitor<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0
+                    .insert(field.name().to_string(), format!("{:?}", value));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.insert(field.name().to_string(), value.to_string());
+            }
+        }
+
+        struct CaptureSubscriber {
+            next_id: AtomicU64,
+            spans: Spans,
+        }
+
+        impl tracing::Subscriber for CaptureSubscriber {
+            fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = HashMap::new();
+                span.record(&mut FieldsVisitor(&mut fields));
+                let mut spans = self.spans.lock().unwrap();
+                spans.push((span.metadata().name(), fields));
+                Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+                values.record(&mut FieldsVisitor(fields));
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &tracing::Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        setup_blacklist();
+        let spans: Spans = Arc::default();
+        let subscriber = CaptureSubscriber {
+            next_id: AtomicU64::new(0),
+            spans: Arc::clone(&spans),
+        };
+        let secret = "hunter2hunter2";
+        let pwd = SecretString::new(secret.to_string().into());
+
+        tracing::subscriber::with_default(subscriber, || {
+            #[cfg(feature = "async")]
+            evaluate_password_strength(&pwd, None);
+
+            #[cfg(not(feature = "async"))]
+            evaluate_password_strength(&pwd);
+        });
+
+        let spans = spans.lock().unwrap();
+        assert_eq!(spans[0].0, "evaluate");
+        let sections: Vec<&HashMap<String, String>> = spans
+            .iter()
+            .filter(|(name, _)| *name == "section")
+            .map(|(_, fields)| fields)
+            .collect();
+        let names: Vec<&str> = sections.iter().map(|f| f["section"].as_str()).collect();
+        assert_eq!(
+            names,
+            ["blacklist", "denylist", "length", "variety", "pattern"]
+        );
+        for fields in &sections {
+            assert!(["passed", "failed", "error"].contains(&fields["outcome"].as_str()));
+            assert!(fields.contains_key("duration_us"));
+        }
+        let variety = sections.iter().find(|f| f["section"] == "variety").unwrap();
+        assert_eq!(variety["outcome"], "failed");
+        assert!(
+            spans
+                .iter()
+                .flat_map(|(_, fields)| fields.values())
+                .all(|v| !v.contains(secret))
+        );
+
+        cleanup_blacklist();
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    #[serial]
+    fn test_evaluate_deny_patterns() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default()
+            .with_deny_patterns(["2024", "(?i)^acme[-_]?\\w+$"])
+            .unwrap();
+        let forbidden = "Password matches a forbidden pattern".to_string();
+
+        for (pwd, denied) in [
+            ("Winter2024!Xq", true),
+            ("ACME_Portal", true),
+            ("Kx7#mQ2!vR9z", false),
+            ("my-acme-login", false),
+        ] {
+            let pwd = SecretString::new(pwd.to_string().into());
+
+            #[cfg(feature = "async")]
+            let evaluation = evaluate_password_strength_with_config(&pwd, &config, None);
+
+            #[cfg(not(feature = "async"))]
+            let evaluation = evaluate_password_strength_with_config(&pwd, &config);
+
+            assert_eq!(evaluation.reasons.contains(&forbidden), denied);
+        }
+
+        cleanup_blacklist();
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    #[serial]
+    fn test_evaluate_normalization_forms_score_identically() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let temp_file = setup_with_tempfile(&["caf\u{00e9}caf\u{00e9}"]);
+        set_env("PWD_BLACKLIST_PATH", temp_file.path().to_str().unwrap());
+        let _ = crate::blacklist::init_blacklist();
+
+        let composed = SecretString::new("Caf\u{00e9}Cr\u{00e8}me42!".to_string().into());
+        let decomposed = SecretString::new("Cafe\u{0301}Cre\u{0300}me42!".to_string().into());
+
+        #[cfg(feature = "async")]
+        let (a, b) = (
+            evaluate_password_strength(&composed, None),
+            evaluate_password_strength(&decomposed, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (a, b) = (
+            evaluate_password_strength(&composed),
+            evaluate_password_strength(&decomposed),
+        );
+
+        assert_eq!(a.score.map(|s| s.value()), b.score.map(|s| s.value()));
+        assert_eq!(a.reasons, b.reasons);
+
+        let blacklisted = SecretString::new("cafe\u{0301}cafe\u{0301}".to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength(&blacklisted, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength(&blacklisted);
+
+        assert!(evaluation.reasons.iter().any(|r| r.contains("common")));
+
+        cleanup_blacklist();
+    }
+
+    /// Statistics sweep used by the scoring properties: every combination
+    /// of class presence, with lengths from 0 to 64 bytes.
+    fn stats_grid() -> impl Iterator<Item = CharStats> {
+        (0..16u8).flat_map(|mask| {
+            (0..=64).map(move |len| {
+                let present = |bit: u8| usize::from(mask & (1 << bit) != 0 && len > 0);
+                CharStats {
+                    byte_len: len,
+                    char_len: len,
+                    unique: len.min(20),
+                    counts: CharClassCounts {
+                        upper: present(0),
+                        lower: present(1),
+                        digit: present(2),
+                        special: present(3) * 2,
+                        other: 0,
+                    },
+                }
+            })
+        })
+    }
+
+    const REASON_SETS: [&[ReasonCode]; 4] = [
+        &[],
+        &[ReasonCode::Sequential],
+        &[ReasonCode::LowUniqueness],
+        &[
+            ReasonCode::Blacklisted,
+            ReasonCode::TooShort,
+            ReasonCode::Repetitive,
+        ],
+    ];
+
+    #[test]
+    fn test_score_from_components_bounded() {
+        let harsh = EvaluatorConfig::default().with_penalty(ReasonCode::Blacklisted, 1_000);
+        for config in [EvaluatorConfig::default(), harsh] {
+            for stats in stats_grid() {
+                for reasons in REASON_SETS {
+                    let score = score_from_components(&stats, reasons, &config);
+                    assert!((0..=100).contains(&score), "{} for {:?}", score, stats);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_score_from_components_monotonic_in_length() {
+        let config = EvaluatorConfig::default();
+        for reasons in REASON_SETS {
+            let mut previous: Option<(CharStats, i64)> = None;
+            for stats in stats_grid() {
+                let score = score_from_components(&stats, reasons, &config);
+                if let Some((prev_stats, prev_score)) = &previous
+                    && prev_stats.counts == stats.counts
+                    && prev_stats.unique <= stats.unique
+                {
+                    assert!(
+                        score >= *prev_score,
+                        "{:?} scored below {:?}",
+                        stats,
+                        prev_stats
+                    );
+                }
+                previous = Some((stats, score));
+            }
+        }
+    }
+
+    #[test]
+    fn test_score_from_components_strongest_is_capped() {
+        let stats = CharStats::compute(&"Kx7#mQ2!vR9zLp4&Wj6^".repeat(2));
+        assert_eq!(
+            score_from_components(&stats, &[], &EvaluatorConfig::default()),
+            100
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_dictionary_check_opt_in() {
+        setup_blacklist();
+        crate::dictionary::reset_dictionary_for_testing();
+        let _ = crate::dictionary::init_dictionary_from_iter(["elephant"]);
+        let pwd = SecretString::new("Elephant9".to_string().into());
+        let enabled = EvaluatorConfig {
+            sections: [Section::DEFAULT.as_slice(), &[Section::Dictionary]].concat(),
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let (default, checked) = (
+            evaluate_password_strength(&pwd, None),
+            evaluate_password_strength_with_config(&pwd, &enabled, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (default, checked) = (
+            evaluate_password_strength(&pwd),
+            evaluate_password_strength_with_config(&pwd, &enabled),
+        );
+
+        assert!(
+            !default
+                .reasons
+                .iter()
+                .any(|r| r.contains("dictionary word"))
+        );
+        assert!(
+            checked
+                .reasons
+                .iter()
+                .any(|r| r.contains("dictionary word"))
+        );
+
+        crate::dictionary::reset_dictionary_for_testing();
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_advisory_reasons_do_not_reduce_score() {
+        setup_blacklist();
+        let pwd = SecretString::new("Kx7#mQ2!v".to_string().into());
+        let advised = EvaluatorConfig {
+            length_advisory_band: 4,
+            ..Default::default()
+        }
+        .with_penalty(ReasonCode::MarginalLength, 50);
+
+        #[cfg(feature = "async")]
+        let (baseline, advised) = (
+            evaluate_password_strength(&pwd, None),
+            evaluate_password_strength_with_config(&pwd, &advised, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (baseline, advised) = (
+            evaluate_password_strength(&pwd),
+            evaluate_password_strength_with_config(&pwd, &advised),
+        );
+
+        assert!(advised.reasons.iter().any(|r| r.contains("consider 12+")));
+        assert_eq!(advised.reasons.len(), baseline.reasons.len() + 1);
+        assert_eq!(
+            advised.score.map(|s| s.value()),
+            baseline.score.map(|s| s.value())
+        );
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    fn test_score_from_components_custom_unique_bonus() {
+        // 10 unique characters: below both default thresholds
+        let stats = CharStats::compute("Kx7#mQ2!vR");
+        assert_eq!(stats.unique, 10);
+        let default = score_from_components(&stats, &[], &EvaluatorConfig::default());
+
+        let config = EvaluatorConfig {
+            unique_char_bonuses: vec![(10, 8)],
+            ..Default::default()
+        };
+        assert_eq!(score_from_components(&stats, &[], &config), default + 8);
+
+        let disabled = EvaluatorConfig {
+            unique_char_bonuses: Vec::new(),
+            ..Default::default()
+        };
+        let long = CharStats::compute("Kx7#mQ2!vR9$wL4@");
+        assert_eq!(
+            score_from_components(&long, &[], &disabled) + 10,
+            score_from_components(&long, &[], &EvaluatorConfig::default())
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_without_bonuses_reflects_only_penalties() {
+        setup_blacklist();
+        let flat = EvaluatorConfig {
+            bonuses: crate::config::ScoreBonuses {
+                length: false,
+                variety: false,
+                extra_length: false,
+                multi_special: false,
+                unique_chars: false,
+            },
+            ..Default::default()
+        };
+        let evaluate = |pwd: &str, config: &EvaluatorConfig| {
+            evaluate_password_strength_with_config(
+                &SecretString::new(pwd.to_string().into()),
+                config,
+                #[cfg(feature = "async")]
+                None,
+            )
+            .score
+            .map(|s| s.value())
+        };
+
+        // Compliant: graded below the maximum, full marks without bonuses
+        let graded = evaluate("Kx7#mQ2!v", &EvaluatorConfig::default()).unwrap();
+        assert!(graded < 100, "{graded}");
+        assert_eq!(evaluate("Kx7#mQ2!v", &flat), Some(100));
+        assert_eq!(evaluate("Kx7#mQ2!vR9$wL4@", &flat), Some(100));
+
+        // Failures still cost their penalties
+        assert!(evaluate("Pass1234word!", &flat).unwrap() < 100);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_passphrase_mode_scores_long_phrases_as_strong() {
+        setup_blacklist();
+        let phrase = "the quick brown fox jumps";
+        let passphrases = EvaluatorConfig {
+            passphrase_mode: true,
+            ..Default::default()
+        };
+        let evaluate = |pwd: &str, config: &EvaluatorConfig| {
+            evaluate_password_strength_with_config(
+                &SecretString::new(pwd.to_string().into()),
+                config,
+                #[cfg(feature = "async")]
+                None,
+            )
+        };
+
+        // Spaces are not special characters
+        let stats = CharStats::compute_with_specials(phrase, &passphrases.special_chars);
+        assert_eq!(stats.counts.special, 0);
+
+        let default = evaluate(phrase, &EvaluatorConfig::default());
+        assert!(default.reasons.iter().any(|r| r.contains("uppercase")));
+        assert_eq!(default.strength(), PasswordStrength::WEAK);
+
+        let passphrase = evaluate(phrase, &passphrases);
+        assert!(passphrase.reasons.is_empty(), "{:?}", passphrase.reasons);
+        assert!(is_acceptable_with_config(
+            &SecretString::new(phrase.to_string().into()),
+            &passphrases,
+            PasswordStrength::STRONG
+        ));
+
+        // Below the threshold the usual requirements apply
+        let short = evaluate("quick brown fox", &passphrases);
+        assert!(short.reasons.iter().any(|r| r.contains("uppercase")));
+
+        // Patterns and the blacklist still count
+        let repeated = evaluate("aaaa bbbb cccc dddd eeee", &passphrases);
+        assert_eq!(repeated.strength(), PasswordStrength::WEAK);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    fn test_score_from_components_penalizes_by_severity() {
+        let stats = CharStats::compute("Kx7#mQ2!vR9z");
+        let config = EvaluatorConfig::default();
+        let clean = score_from_components(&stats, &[], &config);
+        assert_eq!(
+            score_from_components(&stats, &[ReasonCode::MarginalLength], &config),
+            clean
+        );
+        assert!(score_from_components(&stats, &[ReasonCode::MixedScript], &config) < clean);
+        assert!(score_from_components(&stats, &[ReasonCode::Sequential], &config) < clean);
+    }
+
+    #[cfg(feature = "timing")]
+    #[test]
+    #[serial]
+    fn test_detailed_evaluation_records_timing() {
+        setup_blacklist();
+        let pwd = SecretString::new("Kx7#mQ2!vR9$wL4@".to_string().into());
+        let config = EvaluatorConfig::default();
+
+        #[cfg(feature = "async")]
+        let detailed = evaluate_password_strength_detailed(&pwd, &config, None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&pwd, &config);
+
+        let timing = &detailed.timing;
+        assert!(timing.elapsed > std::time::Duration::ZERO);
+        let names: Vec<_> = timing.sections.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, config.section_names());
+        let total: std::time::Duration = timing.sections.iter().map(|(_, d)| *d).sum();
+        assert!(total <= timing.elapsed);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_is_acceptable_at_and_below_threshold() {
+        setup_blacklist();
+        // MEDIUM: long enough with all classes, but sequential
+        let pwd = SecretString::new("Pass1234word!".to_string().into());
+        assert_eq!(
+            evaluate_str("Pass1234word!").strength(),
+            PasswordStrength::MEDIUM
+        );
+
+        assert!(is_acceptable(&pwd, PasswordStrength::WEAK));
+        assert!(is_acceptable(&pwd, PasswordStrength::MEDIUM));
+        assert!(!is_acceptable(&pwd, PasswordStrength::STRONG));
+
+        // A stricter config pushes the same password below MEDIUM
+        let strict = EvaluatorConfig::default().with_penalty(ReasonCode::Sequential, 30);
+        assert!(!is_acceptable_with_config(
+            &pwd,
+            &strict,
+            PasswordStrength::MEDIUM
+        ));
+        assert!(is_acceptable_with_config(
+            &pwd,
+            &strict,
+            PasswordStrength::WEAK
+        ));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_quick_reject_agrees_with_full_evaluation() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default();
+
+        for pwd in [
+            "",
+            "abc",
+            "password",
+            "QWERTY",
+            "a".repeat(config.max_length + 1).as_str(),
+        ] {
+            let secret = SecretString::new(pwd.to_string().into());
+            assert!(quick_reject(&secret, &config), "{pwd}");
+            assert!(
+                !is_acceptable_with_config(&secret, &config, PasswordStrength::MEDIUM),
+                "{pwd}"
+            );
+        }
+        for pwd in ["Kx7#mQ2!vR9$wL4@", "Tr0ub4dor&3-Kx7#"] {
+            let secret = SecretString::new(pwd.to_string().into());
+            assert!(!quick_reject(&secret, &config), "{pwd}");
+            assert!(
+                is_acceptable_with_config(&secret, &config, PasswordStrength::STRONG),
+                "{pwd}"
+            );
+        }
+
+        // Disabled sections are not consulted
+        let no_blacklist = EvaluatorConfig {
+            sections: vec![Section::Length],
+            ..Default::default()
+        };
+        let common = SecretString::new("password".to_string().into());
+        assert!(!quick_reject(&common, &no_blacklist));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_strength_only_matches_full_evaluation() {
+        setup_blacklist();
+        let configs = [
+            EvaluatorConfig::default(),
+            EvaluatorConfig {
+                fail_fast: true,
+                ..Default::default()
+            },
+            EvaluatorConfig::default().with_min_score(90),
+            EvaluatorConfig {
+                passphrase_mode: true,
+                ..Default::default()
+            },
+        ];
+        let long = "a".repeat(EvaluatorConfig::default().max_length + 1);
+        let passwords = [
+            "",
+            "abc",
+            "password",
+            "Password1!",
+            "aaaaaaaaaa",
+            "abcdef123",
+            "Kx7#mQ2!",
+            "Kx7mQ2vRpL",
+            "Kx7#mQ2!vR9$wL4@",
+            "Kx7#mQ2!vR9$wL4@zT8&bN5%",
+            "the quick brown fox jumps",
+            "12345678901",
+            long.as_str(),
+        ];
+        for config in &configs {
+            for pwd in passwords {
+                let secret = SecretString::new(pwd.to_string().into());
+                let full = evaluate_password_strength_with_config(
+                    &secret,
+                    config,
+                    #[cfg(feature = "async")]
+                    None,
+                );
+                assert_eq!(strength_only(&secret, config), full.strength(), "{pwd}");
+            }
+        }
+
+        #[cfg(feature = "regex")]
+        {
+            let config = EvaluatorConfig::default()
+                .with_deny_patterns(["wL4@"])
+                .unwrap();
+            let secret = SecretString::new("Kx7#mQ2!vR9$wL4@".to_string().into());
+            let full = evaluate_password_strength_with_config(
+                &secret,
+                &config,
+                #[cfg(feature = "async")]
+                None,
+            );
+            assert_eq!(full.reasons, ["Password matches a forbidden pattern"]);
+            assert_eq!(strength_only(&secret, &config), full.strength());
+        }
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_missing_behaviors() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let pwd = SecretString::new("Kx7#mQ2!vR9$wL4@".to_string().into());
+        let evaluate = |behavior| {
+            let config = EvaluatorConfig {
+                blacklist_missing: behavior,
+                ..Default::default()
+            };
+            try_evaluate_password_strength_with_config(
+                &pwd,
+                &config,
+                #[cfg(feature = "async")]
+                None,
+            )
+        };
+
+        let ignored = evaluate(BlacklistMissingBehavior::Ignore).unwrap();
+        assert!(ignored.reasons.is_empty());
+
+        // The advisory costs no points
+        let warned = evaluate(BlacklistMissingBehavior::Warn).unwrap();
+        assert_eq!(
+            warned.reasons,
+            ["Blacklist not loaded; common passwords were not checked"]
+        );
+        assert_eq!(
+            warned.score.map(|s| s.value()),
+            ignored.score.map(|s| s.value())
+        );
+        assert_eq!(
+            EvaluatorConfig::default().blacklist_missing,
+            BlacklistMissingBehavior::Warn
+        );
+
+        assert!(matches!(
+            evaluate(BlacklistMissingBehavior::Error),
+            Err(BlacklistError::NotLoaded)
+        ));
+        let strict = EvaluatorConfig {
+            blacklist_missing: BlacklistMissingBehavior::Error,
+            ..Default::default()
+        };
+        let evaluation = evaluate_password_strength_with_config(
+            &pwd,
+            &strict,
+            #[cfg(feature = "async")]
+            None,
+        );
+        assert_eq!(evaluation.reasons, ["Error"]);
+
+        // Once loaded, every behavior evaluates normally
+        setup_blacklist();
+        assert!(
+            evaluate(BlacklistMissingBehavior::Error)
+                .unwrap()
+                .reasons
+                .is_empty()
+        );
+        assert!(
+            evaluate(BlacklistMissingBehavior::Warn)
+                .unwrap()
+                .reasons
+                .is_empty()
+        );
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_strongest_of_picks_highest_score() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default();
+        let candidates: Vec<SecretString> = ["Pass1234word!", "Kx7#mQ2!vR9$wL4@", "abc"]
+            .iter()
+            .map(|p| SecretString::new(p.to_string().into()))
+            .collect();
+
+        let (index, evaluation) = strongest_of(&candidates, &config).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(
+            evaluation.score.map(|s| s.value()),
+            evaluate_str("Kx7#mQ2!vR9$wL4@").score.map(|s| s.value())
+        );
+
+        // Ties go to the first candidate
+        let tied: Vec<SecretString> = ["Kx7#mQ2!vR9$wL4@"; 2]
+            .iter()
+            .map(|p| SecretString::new(p.to_string().into()))
+            .collect();
+        assert_eq!(strongest_of(&tied, &config).unwrap().0, 0);
+        assert!(strongest_of(&[], &config).is_none());
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_min_score_rejects_below_threshold() {
+        setup_blacklist();
+        // 55 points: MEDIUM under the tiers alone
+        let pwd = SecretString::new("Kx7#m1234Q".to_string().into());
+        let default = evaluate_str("Kx7#m1234Q");
+        assert_eq!(default.score.as_ref().map(|s| s.value()), Some(55));
+        assert_eq!(default.strength(), PasswordStrength::MEDIUM);
+
+        let config = EvaluatorConfig::default().with_min_score(60);
+        let result = evaluate_password_strength_with_config(
+            &pwd,
+            &config,
+            #[cfg(feature = "async")]
+            None,
+        );
+        assert_eq!(result.strength(), PasswordStrength::WEAK);
+        assert!(
+            result
+                .reasons
+                .iter()
+                .any(|r| r == "Password score 55 is below the required minimum of 60")
+        );
+        assert!(!is_acceptable_with_config(
+            &pwd,
+            &config,
+            PasswordStrength::MEDIUM
+        ));
+
+        // At or above the minimum nothing changes
+        let config = EvaluatorConfig::default().with_min_score(55);
+        let result = evaluate_password_strength_with_config(
+            &pwd,
+            &config,
+            #[cfg(feature = "async")]
+            None,
+        );
+        assert_eq!(result.score.map(|s| s.value()), Some(55));
+        assert_eq!(result.reasons, default.reasons);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_fingerprint_never_exposes_plaintext() {
+        use crate::config::Fingerprinter;
+        use sha2::{Digest, Sha256};
+
+        setup_blacklist();
+        let plaintext = "quietmoon42";
+        let fingerprint = |password: &str| {
+            let digest = Sha256::digest(format!("server-key:{}", password).as_bytes());
+            digest
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        };
+        let config = EvaluatorConfig {
+            fingerprint: Some(Fingerprinter::new(fingerprint)),
+            ..Default::default()
+        };
+        let pwd = SecretString::new(plaintext.to_string().into());
+
+        #[cfg(feature = "async")]
+        let detailed = evaluate_password_strength_detailed(&pwd, &config, None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&pwd, &config);
+
+        assert_eq!(detailed.fingerprint, Some(fingerprint(plaintext)));
+        assert!(!format!("{:?}", detailed).contains(plaintext));
+        assert!(!format!("{:?}", config).contains(plaintext));
+
+        #[cfg(feature = "async")]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default(), None);
+
+        #[cfg(not(feature = "async"))]
+        let detailed = evaluate_password_strength_detailed(&pwd, &EvaluatorConfig::default());
+
+        assert_eq!(detailed.fingerprint, None);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_special_chars_config_drives_score() {
+        setup_blacklist();
+        let pwd = SecretString::new("Tilde123~~xyZ".to_string().into());
+        let score = |config: &EvaluatorConfig| {
+            let detailed = evaluate_password_strength_detailed(
+                &pwd,
+                config,
+                #[cfg(feature = "async")]
+                None,
+            );
+            (
+                detailed.char_counts.special,
+                detailed.evaluation.score.map(|s| s.value()),
+            )
+        };
+
+        let (specials, default_score) = score(&EvaluatorConfig::default());
+        assert_eq!(specials, 0);
+        let config = EvaluatorConfig {
+            special_chars: "~".to_string(),
+            ..Default::default()
+        };
+        let (specials, tilde_score) = score(&config);
+        assert_eq!(specials, 2);
+        // Variety (+15) and multiple specials (+5), no missing-types penalty (+10)
+        assert_eq!(tilde_score.unwrap() - default_score.unwrap(), 30);
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_bytes_matches_string_path() {
+        setup_blacklist();
+        for input in [
+            "password",
+            "Kx7#mQ2!vR9zLp4&",
+            "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}2024!",
+        ] {
+            let bytes = evaluate_bytes(input.as_bytes());
+            let string = evaluate_str(input);
+            assert_eq!(
+                bytes.score.map(|s| s.value()),
+                string.score.map(|s| s.value()),
+                "{input}"
+            );
+            assert_eq!(bytes.reasons, string.reasons, "{input}");
+        }
+        cleanup_blacklist();
+    }
+
+    #[test]
+    fn test_evaluate_bytes_rejects_invalid_utf8() {
+        let result = evaluate_bytes(b"Kx7#mQ2!\xff\xfevR9$");
+        assert_eq!(result.score.map(|s| s.value()), Some(0));
+        assert_eq!(result.reasons, ["Password is not valid UTF-8"]);
+
+        let config = EvaluatorConfig {
+            max_length: 4,
+            ..Default::default()
+        };
+        let result = evaluate_bytes_with_config(b"\xff\xff\xff\xff\xff", &config);
+        assert_eq!(result.reasons, ["Password too long (maximum 4 bytes)"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_str_and_string_match_secret_path() {
+        setup_blacklist();
+        for input in ["password", "abc", "Kx7#mQ2!vR9zLp4&"] {
+            let pwd = SecretString::new(input.to_string().into());
+
+            #[cfg(feature = "async")]
+            let expected = evaluate_password_strength(&pwd, None);
+
+            #[cfg(not(feature = "async"))]
+            let expected = evaluate_password_strength(&pwd);
+
+            for evaluation in [evaluate_str(input), evaluate_string(input.to_string())] {
+                assert_eq!(
+                    evaluation.score.map(|s| s.value()),
+                    expected.score.as_ref().map(|s| s.value())
+                );
+                assert_eq!(evaluation.reasons, expected.reasons);
+            }
+        }
+        cleanup_blacklist();
+    }
+
+    #[test]
+    fn test_section_names() {
+        assert_eq!(
+            default_section_names(),
+            ["blacklist", "denylist", "length", "variety", "pattern"]
+        );
+        assert_eq!(
+            EvaluatorConfig::default().section_names(),
+            default_section_names()
+        );
+
+        let config = EvaluatorConfig {
+            sections: vec![Section::Length, Section::Dictionary],
+            ..Default::default()
+        };
+        assert_eq!(config.section_names(), ["length", "dictionary"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_custom_section_order() {
+        setup_blacklist();
+        // Fails both length and variety
+        let pwd = SecretString::new("abcxyz".to_string().into());
+        let reordered = EvaluatorConfig {
+            sections: vec![Section::Variety, Section::Length, Section::Blacklist],
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let (default, reordered) = (
+            evaluate_password_strength(&pwd, None),
+            evaluate_password_strength_with_config(&pwd, &reordered, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (default, reordered) = (
+            evaluate_password_strength(&pwd),
+            evaluate_password_strength_with_config(&pwd, &reordered),
+        );
+
+        let position = |reasons: &[String], needle: &str| {
+            reasons.iter().position(|r| r.contains(needle)).unwrap()
+        };
+        assert!(position(&default.reasons, "at least") < position(&default.reasons, "Missing"));
+        assert!(position(&reordered.reasons, "Missing") < position(&reordered.reasons, "at least"));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_blacklist_section_disabled() {
+        setup_blacklist();
+        let pwd = SecretString::new("password".to_string().into());
+        let without_blacklist = EvaluatorConfig {
+            sections: vec![Section::Length, Section::Variety, Section::Pattern],
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &without_blacklist, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &without_blacklist);
+
+        assert!(
+            !evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("known common"))
+        );
+        assert!(evaluation.reasons.iter().any(|r| r.contains("Missing")));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_fail_fast_stops_at_first_failure() {
+        setup_blacklist();
+        // Blacklisted, too short, missing types and sequential
+        let pwd = SecretString::new("123456".to_string().into());
+        let fail_fast = EvaluatorConfig {
+            fail_fast: true,
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let (default, fast) = (
+            evaluate_password_strength(&pwd, None),
+            evaluate_password_strength_with_config(&pwd, &fail_fast, None),
+        );
+
+        #[cfg(not(feature = "async"))]
+        let (default, fast) = (
+            evaluate_password_strength(&pwd),
+            evaluate_password_strength_with_config(&pwd, &fail_fast),
+        );
+
+        assert!(default.reasons.len() > 1);
+        assert_eq!(fast.reasons, vec![default.reasons[0].clone()]);
+        assert_eq!(fast.strength(), PasswordStrength::WEAK);
+        assert_eq!(fast.score.map(|s| s.value()), Some(0));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evaluate_fail_fast_ignores_advisory_reasons() {
+        setup_blacklist();
+        let pwd = SecretString::new("Kx7#mQ2!v".to_string().into());
+        let config = EvaluatorConfig {
+            fail_fast: true,
+            length_advisory_band: 4,
+            ..Default::default()
+        };
+
+        #[cfg(feature = "async")]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &config, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = evaluate_password_strength_with_config(&pwd, &config);
+
+        assert!(
+            evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("consider 12+"))
+        );
+        assert!(evaluation.score.is_some_and(|s| s.value() > 0));
+
+        cleanup_blacklist();
+    }
+
+    #[test]
+    #[serial]
+    fn test_pure_evaluation_ignores_blacklist() {
+        let pwd = SecretString::new("password".to_string().into());
+        let config = EvaluatorConfig::default();
+
+        crate::blacklist::reset_blacklist_for_testing();
+        let without = evaluate_password_strength_pure(&pwd, &config);
+
+        setup_blacklist();
+        let with = evaluate_password_strength_pure(&pwd, &config);
+
+        assert_eq!(
+            without.score.map(|s| s.value()),
+            with.score.map(|s| s.value())
+        );
+        assert_eq!(without.reasons, with.reasons);
+        assert!(!with.reasons.iter().any(|r| r.contains("common")));
+
+        // The regular evaluation does see the loaded blacklist
+        let regular = evaluate_password_strength_with_config(
+            &pwd,
+            &config,
+            #[cfg(feature = "async")]
+            None,
+        );
+        assert!(regular.reasons.iter().any(|r| r.contains("common")));
+
+        cleanup_blacklist();
+    }
+}
+
+#[cfg(all(test, feature = "async"))]
+mod async_tests {
+    use super::*;
+    use pwd_types::PasswordStrength;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+    use serial_test::serial;
+
+    fn setup_with_tempfile(passwords: &[&str]) -> NamedTempFile {
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        for pwd in passwords {
+            writeln!(temp_file, "{}", pwd).expect("Failed to write");
+        }
+        temp_file
+    }
+
+    fn set_env(key: &str, value: &str) {
+        unsafe { std::env::set_var(key, value); }
+    }
+
+    fn remove_env(key: &str) {
+        unsafe { std::env::remove_var(key); }
+    }
 
     fn setup_blacklist() {
         crate::blacklist::reset_blacklist_for_testing();
@@ -364,6 +2565,7 @@ mod async_tests {
         assert_eq!(evaluation.strength(), PasswordStrength::NotEvaluated);
         assert!(evaluation.score.is_none());
         assert!(!evaluation.reasons.is_empty());
+        assert_eq!(evaluation.reasons, vec!["Evaluation cancelled".to_string()]);
 
         cleanup_blacklist();
     }
@@ -399,4 +2601,137 @@ mod async_tests {
 
         cleanup_blacklist();
     }
+
+    /// Async source that reports a fixed set of passwords, or always fails.
+    struct MockAsyncSource {
+        listed: &'static [&'static str],
+        fail: bool,
+    }
+
+    impl AsyncBlacklistSource for MockAsyncSource {
+        async fn contains(&self, password: &str) -> Result<bool, BlacklistError> {
+            tokio::task::yield_now().await;
+            if self.fail {
+                return Err(BlacklistError::Source("unreachable".into()));
+            }
+            Ok(self.listed.contains(&password))
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_evaluate_with_async_source_flags_listed_password() {
+        setup_blacklist();
+        let source = MockAsyncSource {
+            listed: &["Hunter2!Hunter2"],
+            fail: false,
+        };
+        let config = EvaluatorConfig::default();
+
+        let listed = SecretString::new("Hunter2!Hunter2".to_string().into());
+        let evaluation = evaluate_password_strength_with_source(&listed, &config, &source, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            evaluation
+                .reasons
+                .iter()
+                .filter(|r| r.contains("known common"))
+                .count(),
+            1
+        );
+
+        let local = evaluate_password_strength_with_config(&listed, &config, None);
+        assert!(evaluation.score.unwrap().value() < local.score.unwrap().value());
+
+        // Not listed remotely: the local blacklist still applies
+        let common = SecretString::new("password".to_string().into());
+        let evaluation = evaluate_password_strength_with_source(&common, &config, &source, None)
+            .await
+            .unwrap();
+        assert!(
+            evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("known common"))
+        );
+
+        cleanup_blacklist();
+    }
+
+    /// Async source that never answers in time; records when its pending
+    /// lookup is dropped.
+    struct SlowAsyncSource {
+        dropped: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    struct DropFlag(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    impl AsyncBlacklistSource for SlowAsyncSource {
+        async fn contains(&self, _password: &str) -> Result<bool, BlacklistError> {
+            let _flag = DropFlag(self.dropped.clone());
+            tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+            Ok(false)
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_cancellation_aborts_pending_async_source() {
+        setup_blacklist();
+        let dropped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let source = SlowAsyncSource {
+            dropped: dropped.clone(),
+        };
+        let config = EvaluatorConfig::default();
+        let token = CancellationToken::new();
+        let pwd = SecretString::new("TestPass123!".to_string().into());
+
+        let (result, ()) = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            tokio::join!(
+                evaluate_password_strength_with_source(&pwd, &config, &source, Some(token.clone())),
+                async {
+                    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    token.cancel();
+                },
+            )
+        })
+        .await
+        .expect("cancellation should not wait for the lookup");
+
+        let evaluation = result.unwrap();
+        assert_eq!(evaluation.strength(), PasswordStrength::NotEvaluated);
+        assert_eq!(evaluation.reasons, ["Evaluation cancelled"]);
+        assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+
+        cleanup_blacklist();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_evaluate_with_async_source_propagates_error() {
+        setup_blacklist();
+        let source = MockAsyncSource {
+            listed: &[],
+            fail: true,
+        };
+        let pwd = SecretString::new("TestPass123!".to_string().into());
+
+        let result = evaluate_password_strength_with_source(
+            &pwd,
+            &EvaluatorConfig::default(),
+            &source,
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(BlacklistError::Source(_))));
+
+        cleanup_blacklist();
+    }
 }
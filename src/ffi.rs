@@ -0,0 +1,283 @@
+//! C ABI bindings
+//!
+//! Stable `extern "C"` entry points for consumers outside Rust. See
+//! `include/pwd_strength.h` for the matching C declarations.
+//!
+//! # Memory ownership
+//!
+//! Input strings are borrowed for the duration of the call and never
+//! retained. Strings returned through out-parameters are allocated by this
+//! library and must be released with `pwd_strength_free_string`; passing
+//! them to `free()` is undefined behavior.
+//!
+//! No panic ever crosses the boundary: any internal panic is reported as
+//! `PWD_STRENGTH_ERR_PANIC`.
+
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use secrecy::SecretString;
+
+use crate::blacklist::{BlacklistError, init_blacklist_from_path};
+use crate::evaluator::evaluate_password_strength;
+
+/// Success.
+pub const PWD_STRENGTH_OK: c_int = 0;
+/// A required pointer argument was null.
+pub const PWD_STRENGTH_ERR_NULL: c_int = -1;
+/// An input string was not valid UTF-8.
+pub const PWD_STRENGTH_ERR_UTF8: c_int = -2;
+/// The blacklist file does not exist.
+pub const PWD_STRENGTH_ERR_NOT_FOUND: c_int = -3;
+/// The blacklist file could not be read.
+pub const PWD_STRENGTH_ERR_IO: c_int = -4;
+/// The blacklist file is empty.
+pub const PWD_STRENGTH_ERR_EMPTY: c_int = -5;
+/// The password could not be scored (e.g. rejected before evaluation).
+pub const PWD_STRENGTH_ERR_NOT_EVALUATED: c_int = -6;
+/// An internal panic was caught.
+pub const PWD_STRENGTH_ERR_PANIC: c_int = -99;
+
+/// Borrows a C string as `&str`, mapping failures to error codes.
+///
+/// # Safety
+/// `ptr` must be null or point to a NUL-terminated string valid for `'a`.
+unsafe fn borrow_str<'a>(ptr: *const c_char) -> Result<&'a str, c_int> {
+    if ptr.is_null() {
+        return Err(PWD_STRENGTH_ERR_NULL);
+    }
+    // SAFETY: non-null and NUL-terminated per the caller contract
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| PWD_STRENGTH_ERR_UTF8)
+}
+
+fn blacklist_error_code(err: &BlacklistError) -> c_int {
+    match err {
+        BlacklistError::FileNotFound(_) => PWD_STRENGTH_ERR_NOT_FOUND,
+        BlacklistError::ReadError(_) | BlacklistError::Source(_) | BlacklistError::NotLoaded => {
+            PWD_STRENGTH_ERR_IO
+        }
+        BlacklistError::EmptyFile | BlacklistError::SuspiciouslySmall { .. } => {
+            PWD_STRENGTH_ERR_EMPTY
+        }
+    }
+}
+
+/// Evaluates `pwd` and returns its score and reasons.
+fn evaluate(pwd: &str) -> (Option<i64>, Vec<String>) {
+    let password = SecretString::new(pwd.to_string().into());
+
+    #[cfg(feature = "async")]
+    let evaluation = evaluate_password_strength(&password, None);
+
+    #[cfg(not(feature = "async"))]
+    let evaluation = evaluate_password_strength(&password);
+
+    (evaluation.score.map(|s| s.value()), evaluation.reasons)
+}
+
+/// Loads the blacklist from the file at `path`.
+///
+/// Returns `PWD_STRENGTH_OK` on success (also when already initialized)
+/// or a negative `PWD_STRENGTH_ERR_*` code.
+///
+/// # Safety
+/// `path` must be null or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pwd_strength_init_blacklist(path: *const c_char) -> c_int {
+    catch_unwind(AssertUnwindSafe(|| {
+        // SAFETY: forwarded caller contract
+        let path = match unsafe { borrow_str(path) } {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+        match init_blacklist_from_path(path) {
+            Ok(_) => PWD_STRENGTH_OK,
+            Err(e) => blacklist_error_code(&e),
+        }
+    }))
+    .unwrap_or(PWD_STRENGTH_ERR_PANIC)
+}
+
+/// Evaluates `pwd` and writes its score (0-100) to `out_score`.
+///
+/// Returns `PWD_STRENGTH_OK` on success or a negative `PWD_STRENGTH_ERR_*`
+/// code, in which case `out_score` is left untouched.
+///
+/// # Safety
+/// `pwd` must be null or a valid NUL-terminated string; `out_score` must be
+/// null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pwd_strength_evaluate(pwd: *const c_char, out_score: *mut c_int) -> c_int {
+    // SAFETY: forwarded caller contract
+    unsafe { pwd_strength_evaluate_with_reasons(pwd, out_score, std::ptr::null_mut()) }
+}
+
+/// Evaluates `pwd`, writing its score to `out_score` and, if `out_reasons`
+/// is non-null, a newly allocated newline-separated list of reasons.
+///
+/// The reasons string is owned by the caller and must be released with
+/// `pwd_strength_free_string`. It is set only when `PWD_STRENGTH_OK` or
+/// `PWD_STRENGTH_ERR_NOT_EVALUATED` is returned, and is empty when there
+/// are no reasons.
+///
+/// # Safety
+/// `pwd` must be null or a valid NUL-terminated string; `out_score` must be
+/// null or valid for writes; `out_reasons` must be null or valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pwd_strength_evaluate_with_reasons(
+    pwd: *const c_char,
+    out_score: *mut c_int,
+    out_reasons: *mut *mut c_char,
+) -> c_int {
+    catch_unwind(AssertUnwindSafe(|| {
+        if out_score.is_null() {
+            return PWD_STRENGTH_ERR_NULL;
+        }
+        // SAFETY: forwarded caller contract
+        let pwd = match unsafe { borrow_str(pwd) } {
+            Ok(p) => p,
+            Err(code) => return code,
+        };
+
+        let (score, reasons) = evaluate(pwd);
+
+        if !out_reasons.is_null() {
+            // Interior NULs cannot occur in our reasons; strip defensively
+            let joined = reasons.join("\n").replace('\0', "");
+            let c_reasons = CString::new(joined).unwrap_or_default();
+            // SAFETY: non-null and valid for writes per the caller contract
+            unsafe { *out_reasons = c_reasons.into_raw() };
+        }
+
+        match score {
+            Some(s) => {
+                // SAFETY: checked non-null above
+                unsafe { *out_score = s.clamp(0, 100) as c_int };
+                PWD_STRENGTH_OK
+            }
+            None => PWD_STRENGTH_ERR_NOT_EVALUATED,
+        }
+    }))
+    .unwrap_or(PWD_STRENGTH_ERR_PANIC)
+}
+
+/// Releases a string returned by this library. Null is a no-op.
+///
+/// # Safety
+/// `s` must be null or a pointer previously returned by this library that
+/// has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pwd_strength_free_string(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+    // SAFETY: allocated by CString::into_raw in this module
+    drop(unsafe { CString::from_raw(s) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn setup_blacklist() -> NamedTempFile {
+        crate::blacklist::reset_blacklist_for_testing();
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        writeln!(temp_file, "password").expect("Failed to write");
+        writeln!(temp_file, "qwerty").expect("Failed to write");
+        temp_file
+    }
+
+    #[test]
+    #[serial]
+    fn test_ffi_init_blacklist() {
+        let temp_file = setup_blacklist();
+        let path = CString::new(temp_file.path().to_str().unwrap()).unwrap();
+
+        let code = unsafe { pwd_strength_init_blacklist(path.as_ptr()) };
+        assert_eq!(code, PWD_STRENGTH_OK);
+        assert!(crate::blacklist::is_blacklisted("qwerty"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_ffi_init_blacklist_errors() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let missing = CString::new("/nonexistent/path/blacklist.txt").unwrap();
+
+        assert_eq!(
+            unsafe { pwd_strength_init_blacklist(std::ptr::null()) },
+            PWD_STRENGTH_ERR_NULL
+        );
+        assert_eq!(
+            unsafe { pwd_strength_init_blacklist(missing.as_ptr()) },
+            PWD_STRENGTH_ERR_NOT_FOUND
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_ffi_evaluate() {
+        let temp_file = setup_blacklist();
+        let _ = init_blacklist_from_path(temp_file.path());
+        let pwd = CString::new("VeryStrongPassword123!@#").unwrap();
+        let mut score: c_int = -1;
+
+        let code = unsafe { pwd_strength_evaluate(pwd.as_ptr(), &mut score) };
+        assert_eq!(code, PWD_STRENGTH_OK);
+        assert!((70..=100).contains(&score));
+    }
+
+    #[test]
+    #[serial]
+    fn test_ffi_evaluate_with_reasons() {
+        let temp_file = setup_blacklist();
+        let _ = init_blacklist_from_path(temp_file.path());
+        let pwd = CString::new("password").unwrap();
+        let mut score: c_int = -1;
+        let mut reasons: *mut c_char = std::ptr::null_mut();
+
+        let code =
+            unsafe { pwd_strength_evaluate_with_reasons(pwd.as_ptr(), &mut score, &mut reasons) };
+        assert_eq!(code, PWD_STRENGTH_OK);
+        assert!(!reasons.is_null());
+
+        let text = unsafe { CStr::from_ptr(reasons) }
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(text.lines().any(|r| r.contains("common")));
+
+        unsafe { pwd_strength_free_string(reasons) };
+    }
+
+    #[test]
+    fn test_ffi_evaluate_null_and_invalid_input() {
+        let mut score: c_int = 42;
+        let invalid = [0xffu8 as c_char, 0];
+        let pwd = CString::new("abc").unwrap();
+
+        assert_eq!(
+            unsafe { pwd_strength_evaluate(std::ptr::null(), &mut score) },
+            PWD_STRENGTH_ERR_NULL
+        );
+        assert_eq!(
+            unsafe { pwd_strength_evaluate(pwd.as_ptr(), std::ptr::null_mut()) },
+            PWD_STRENGTH_ERR_NULL
+        );
+        assert_eq!(
+            unsafe { pwd_strength_evaluate(invalid.as_ptr(), &mut score) },
+            PWD_STRENGTH_ERR_UTF8
+        );
+        assert_eq!(score, 42);
+    }
+
+    #[test]
+    fn test_ffi_free_null_is_noop() {
+        unsafe { pwd_strength_free_string(std::ptr::null_mut()) };
+    }
+}
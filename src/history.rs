@@ -0,0 +1,309 @@
+//! Password history - detects reuse of a previously stored password.
+
+use std::fmt;
+use std::sync::Arc;
+
+use pwd_types::{PasswordEvaluation, PasswordScore};
+use secrecy::{ExposeSecret, SecretString};
+use sha2::{Digest, Sha256};
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::evaluate_password_strength_with_config;
+use crate::score::ScoreExt;
+
+/// Caller-supplied hash of the UTF-8 password bytes.
+pub type HashFn = Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
+/// Hash function used to produce a `PasswordHash`.
+#[derive(Clone)]
+pub enum HashAlgorithm {
+    /// SHA-256 of the UTF-8 password bytes.
+    Sha256,
+    /// Caller-supplied function of the UTF-8 password bytes. As a closure
+    /// it can capture the salt or key of the stored entry; see
+    /// `HashAlgorithm::custom`.
+    Custom(HashFn),
+}
+
+impl HashAlgorithm {
+    /// Wraps `f` as a `Custom` algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let salt = stored.salt.clone();
+    /// let algorithm = HashAlgorithm::custom(move |bytes| salted_hash(&salt, bytes));
+    /// let hash = PasswordHash::new(algorithm, stored.digest.clone());
+    /// ```
+    pub fn custom(f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static) -> Self {
+        HashAlgorithm::Custom(Arc::new(f))
+    }
+
+    /// Hashes `bytes` with this algorithm.
+    pub fn hash(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(bytes).to_vec(),
+            HashAlgorithm::Custom(f) => f(bytes),
+        }
+    }
+}
+
+impl fmt::Debug for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashAlgorithm::Sha256 => f.write_str("Sha256"),
+            HashAlgorithm::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Stored hash of a previous password.
+#[derive(Debug, Clone)]
+pub struct PasswordHash {
+    algorithm: HashAlgorithm,
+    digest: Vec<u8>,
+}
+
+impl PasswordHash {
+    /// Wraps an existing digest produced by `algorithm`.
+    pub fn new(algorithm: HashAlgorithm, digest: impl Into<Vec<u8>>) -> Self {
+        Self {
+            algorithm,
+            digest: digest.into(),
+        }
+    }
+
+    /// Parses a hex-encoded digest produced by `algorithm`.
+    ///
+    /// Returns `None` if `hex` is not valid hexadecimal.
+    pub fn from_hex(algorithm: HashAlgorithm, hex: &str) -> Option<Self> {
+        if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+            return None;
+        }
+        let digest = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        Some(Self::new(algorithm, digest))
+    }
+
+    /// Hashes `password` with `algorithm`.
+    pub fn compute(algorithm: HashAlgorithm, password: &SecretString) -> Self {
+        let digest = algorithm.hash(password.expose_secret().as_bytes());
+        Self::new(algorithm, digest)
+    }
+
+    /// Returns the algorithm that produced this hash.
+    pub fn algorithm(&self) -> &HashAlgorithm {
+        &self.algorithm
+    }
+
+    /// Returns the raw digest.
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    /// Returns `true` if `password` hashes to this digest.
+    pub fn matches(&self, password: &SecretString) -> bool {
+        let candidate = self.algorithm.hash(password.expose_secret().as_bytes());
+        constant_time_eq(&candidate, &self.digest)
+    }
+}
+
+/// Evaluates a new password under the default configuration, rejecting it
+/// if it matches any hash in `history`.
+///
+/// The candidate is hashed with each entry's own algorithm. A reused
+/// password scores `0` with the reason "Password was used recently".
+/// Passwords that were not evaluated (`score: None`, e.g. longer than
+/// `max_length`) are returned as is, without hashing.
+///
+/// # Arguments
+/// * `password` - The new password to evaluate
+/// * `history` - Hashes of the user's previous passwords
+///
+/// # Returns
+/// A `PasswordEvaluation` containing score and reasons.
+pub fn evaluate_with_history(
+    password: &SecretString,
+    history: &[PasswordHash],
+) -> PasswordEvaluation {
+    evaluate_with_history_with_config(password, history, &EvaluatorConfig::default())
+}
+
+/// Like `evaluate_with_history`, evaluating under `config`.
+pub fn evaluate_with_history_with_config(
+    password: &SecretString,
+    history: &[PasswordHash],
+    config: &EvaluatorConfig,
+) -> PasswordEvaluation {
+    let mut evaluation = evaluate_password_strength_with_config(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    );
+    // Oversized input is NotEvaluated; hashing it would undo the length guard
+    if password.expose_secret().len() > config.max_length || evaluation.score.is_none() {
+        return evaluation;
+    }
+
+    if history.iter().any(|h| h.matches(password)) {
+        evaluation
+            .reasons
+            .push("Password was used recently".to_string());
+        evaluation.score = Some(PasswordScore::new_clamped(0));
+    }
+    evaluation
+}
+
+/// Compares two digests without short-circuiting on the first difference.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{secret, setup_blacklist};
+    use pwd_types::PasswordStrength;
+    use serial_test::serial;
+
+    const SUMMER_SHA256: &str = "935cfa60fb49dcfb8cea35da1faec898104ab0c7ca71341cf9aee1b03d26c5ee";
+    const PASSWORD_SHA256: &str =
+        "5e884898da28047151d0e56f8dc6292773603d0d6aabbdd62a11ef721d1542d8";
+
+    fn history() -> Vec<PasswordHash> {
+        [SUMMER_SHA256, PASSWORD_SHA256]
+            .iter()
+            .map(|hex| PasswordHash::from_hex(HashAlgorithm::Sha256, hex).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sha256_known_digest() {
+        let hash = PasswordHash::compute(HashAlgorithm::Sha256, &secret("password"));
+        assert_eq!(
+            hash.digest(),
+            PasswordHash::from_hex(HashAlgorithm::Sha256, PASSWORD_SHA256)
+                .unwrap()
+                .digest()
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_reused_password_flagged() {
+        setup_blacklist();
+        let evaluation = evaluate_with_history(&secret("Summer2023!"), &history());
+        assert!(
+            evaluation
+                .reasons
+                .contains(&"Password was used recently".to_string())
+        );
+        assert_eq!(evaluation.strength(), PasswordStrength::WEAK);
+    }
+
+    #[test]
+    #[serial]
+    fn test_new_password_passes() {
+        setup_blacklist();
+        let evaluation = evaluate_with_history(&secret("Kx7#mQ2!vR9z"), &history());
+        assert!(
+            !evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("used recently"))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_custom_hash_function() {
+        setup_blacklist();
+        fn reversed(bytes: &[u8]) -> Vec<u8> {
+            bytes.iter().rev().copied().collect()
+        }
+        let history = [PasswordHash::new(
+            HashAlgorithm::custom(reversed),
+            b"!3202remmuS".to_vec(),
+        )];
+
+        let evaluation = evaluate_with_history(&secret("Summer2023!"), &history);
+        assert!(
+            evaluation
+                .reasons
+                .contains(&"Password was used recently".to_string())
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_salted_history_entries() {
+        setup_blacklist();
+        // Each entry carries its own salt, captured by its algorithm
+        let salted = |salt: &'static [u8]| {
+            HashAlgorithm::custom(move |bytes| {
+                let mut hasher = Sha256::new();
+                hasher.update(salt);
+                hasher.update(bytes);
+                hasher.finalize().to_vec()
+            })
+        };
+        let history = [
+            PasswordHash::compute(salted(b"salt-one"), &secret("Winter2022!")),
+            PasswordHash::compute(salted(b"salt-two"), &secret("Summer2023!")),
+        ];
+        assert_ne!(
+            history[1].digest(),
+            PasswordHash::compute(salted(b"salt-one"), &secret("Summer2023!")).digest()
+        );
+        assert!(history[1].matches(&secret("Summer2023!")));
+        assert!(!history[0].matches(&secret("Summer2023!")));
+        assert_eq!(format!("{:?}", history[0].algorithm()), "Custom(..)");
+
+        let config = EvaluatorConfig::default().with_min_score(10);
+        let reused = evaluate_with_history_with_config(&secret("Summer2023!"), &history, &config);
+        assert!(
+            reused
+                .reasons
+                .contains(&"Password was used recently".to_string())
+        );
+        let fresh = evaluate_with_history_with_config(&secret("Kx7#mQ2!vR9z"), &history, &config);
+        assert!(!fresh.reasons.iter().any(|r| r.contains("used recently")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_oversized_reused_password_stays_not_evaluated() {
+        setup_blacklist();
+        let hashed = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = hashed.clone();
+        let algorithm = HashAlgorithm::custom(move |bytes| {
+            flag.store(true, std::sync::atomic::Ordering::SeqCst);
+            Sha256::digest(bytes).to_vec()
+        });
+        let config = EvaluatorConfig::default();
+        let long = secret(&"Kx7#".repeat(config.max_length));
+        let history = [PasswordHash::new(
+            algorithm,
+            Sha256::digest(long.expose_secret().as_bytes()).to_vec(),
+        )];
+
+        let evaluation = evaluate_with_history_with_config(&long, &history, &config);
+        assert_eq!(evaluation.strength(), PasswordStrength::NotEvaluated);
+        assert!(
+            !evaluation
+                .reasons
+                .iter()
+                .any(|r| r.contains("used recently"))
+        );
+        assert!(!hashed.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_from_hex_rejects_invalid() {
+        assert!(PasswordHash::from_hex(HashAlgorithm::Sha256, "abc").is_none());
+        assert!(PasswordHash::from_hex(HashAlgorithm::Sha256, "zz").is_none());
+    }
+}
@@ -0,0 +1,65 @@
+//! Keyboard layouts - key adjacency used to detect keyboard walks.
+
+/// Keyboard layout used to detect keyboard walks such as `"qwer"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum KeyboardLayout {
+    /// US/UK QWERTY.
+    #[default]
+    Qwerty,
+    /// French AZERTY.
+    Azerty,
+    /// German/Central European QWERTZ.
+    Qwertz,
+    /// Simplified Dvorak.
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    /// Letter rows of the layout, top to bottom.
+    fn rows(self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => &["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Qwertz => &["qwertzuiop", "asdfghjkl", "yxcvbnm"],
+            KeyboardLayout::Dvorak => &["',.pyfgcrl", "aoeuidhtns", ";qjkxbmwvz"],
+        }
+    }
+
+    /// Row and column of `c` (case-insensitive), if it is a letter key.
+    fn position(self, c: char) -> Option<(usize, usize)> {
+        let c = c.to_ascii_lowercase();
+        self.rows()
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.chars().position(|k| k == c).map(|col| (row, col)))
+    }
+
+    /// Returns `true` if `a` and `b` are neighbours on the same row.
+    pub(crate) fn adjacent(self, a: char, b: char) -> bool {
+        match (self.position(a), self.position(b)) {
+            (Some((ra, ca)), Some((rb, cb))) => ra == rb && ca.abs_diff(cb) == 1,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_keys() {
+        assert!(KeyboardLayout::Qwerty.adjacent('q', 'W'));
+        assert!(KeyboardLayout::Qwerty.adjacent('s', 'a'));
+        assert!(!KeyboardLayout::Qwerty.adjacent('q', 's'));
+        assert!(KeyboardLayout::Azerty.adjacent('q', 's'));
+        assert!(KeyboardLayout::Qwertz.adjacent('t', 'z'));
+        assert!(!KeyboardLayout::Qwerty.adjacent('t', 'z'));
+        assert!(!KeyboardLayout::Dvorak.adjacent('1', '2'));
+    }
+}
@@ -5,17 +5,48 @@
 //!
 //! # Features
 //!
+//! - `std` (default): The evaluator, blacklist, dictionary and file loading.
+//!   Without it only the alloc-only [`core`] scoring module is built, for
+//!   `no_std` targets; every other feature enables `std`
 //! - `async` (default): Enables async evaluation with cancellation support
 //! - `tracing`: Enables logging via tracing crate
+//! - `unicode-normalization`: Normalizes passwords and blacklist entries to NFC
+//! - `regex`: Custom deny patterns via `EvaluatorConfig::with_deny_patterns`
+//! - `rayon`: Parses large blacklist files in parallel
+//! - `serde`: Policy files via `EvaluatorConfig::from_toml_str` and `from_json_str`,
+//!   and `to_canonical_json` for snapshot tests
+//! - `cli`: Builds the `pwd-strength` command-line binary
+//! - `cache`: `EvaluationCache`, reusing results for recently seen passwords
+//! - `timing`: Evaluation and per-section durations in `DetailedEvaluation`
+//! - `metrics`: Evaluation outcome hooks via `EvaluatorConfig::observer`
+//! - `ffi`: Exposes a C ABI (see the [`ffi`] module and `include/pwd_strength.h`)
+//!
+//! # WebAssembly
+//!
+//! The crate builds for `wasm32-unknown-unknown` with
+//! `default-features = false, features = ["std"]`. On that target there is no filesystem, so
+//! load the blacklist with `init_blacklist_from_reader` or
+//! `init_blacklist_from_iter` instead of `init_blacklist`; the synchronous
+//! `evaluate_password_strength` is the supported entry point.
+//!
+//! # Secrecy
+//!
+//! No reason, result, report, error or tracing field produced by the
+//! library contains the password, in `Display` or `Debug` form. Reason
+//! messages are fixed texts that may quote limits and scores but never
+//! input. The exception is `EvaluatorConfig::fingerprint`, whose output is
+//! whatever the caller's callback returns.
 //!
 //! # Environment Variables
 //!
 //! - `PWD_BLACKLIST_PATH`: Custom path to blacklist file
-//!   (default: `./assets/blacklist.txt`)
+//!   (default: `./assets/blacklist.txt`). A path set with
+//!   `set_default_blacklist_path` takes precedence.
 //!
 //! # Example
 //!
 //! ```rust,no_run
+//! # #[cfg(feature = "std")] {
 //! use pwd_strength::{init_blacklist, evaluate_password_strength};
 //! use secrecy::SecretString;
 //!
@@ -33,19 +64,155 @@
 //!
 //! println!("Score: {:?}", evaluation.score);
 //! println!("Strength: {:?}", evaluation.strength());
+//! # }
 //! ```
 
-// Re-export types from pwd-types for convenience
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod core;
+
+// Re-export types from pwd-types for convenience.
+// Derives on these types (Clone, PartialEq, Eq, Hash, Ord) have to be added
+// in pwd-types itself; they cannot be implemented from this crate. See
+// `ScoreExt` for score comparisons.
+#[cfg(feature = "std")]
 pub use pwd_types::{PasswordEvaluation, PasswordScore, PasswordStrength};
 
 // Internal modules
+#[cfg(feature = "std")]
+mod advice;
+#[cfg(feature = "std")]
 mod blacklist;
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "std")]
+mod denylist;
+#[cfg(feature = "std")]
+mod dictionary;
+#[cfg(feature = "std")]
+mod diff;
+#[cfg(feature = "std")]
 mod evaluator;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod history;
+mod keyboard;
+#[cfg(feature = "async")]
+mod live;
+mod locale;
+#[cfg(feature = "unicode-normalization")]
+mod normalize;
+#[cfg(feature = "metrics")]
+mod observer;
+#[cfg(feature = "serde")]
+mod policy;
+mod reason;
+#[cfg(feature = "std")]
+mod report;
+#[cfg(feature = "std")]
+mod requirements;
+#[cfg(feature = "std")]
+mod score;
+#[cfg(feature = "std")]
 mod sections;
+#[cfg(feature = "std")]
+mod similarity;
+mod stats;
+#[cfg(feature = "async")]
+mod stream;
 
 // Public API
-pub use blacklist::{init_blacklist, init_blacklist_from_path, get_blacklist, is_blacklisted, BlacklistError};
-pub use evaluator::evaluate_password_strength;
+#[cfg(feature = "std")]
+pub use advice::{Improvement, TierAdvice, next_tier_advice};
+#[cfg(feature = "std")]
+pub use blacklist::{
+    BlacklistError, BlacklistLoadOptions, BlacklistLoadReport, BlacklistSource,
+    DEFAULT_BLACKLIST_PATH, DEFAULT_MAX_ENTRY_LENGTH, DEFAULT_MIN_RELOAD_ENTRIES,
+    DEFAULT_MIN_RELOAD_PERCENT, GlobalBlacklist, clear_default_blacklist_path,
+    extend_blacklist_from_iter, extend_blacklist_from_path, get_blacklist, init_blacklist,
+    init_blacklist_from_iter, init_blacklist_from_path, init_blacklist_from_path_with_options,
+    init_blacklist_from_reader, is_blacklisted, nearest_blacklist_distance, reload_blacklist,
+    reload_blacklist_from_path_with_options, set_default_blacklist_path, with_blacklist,
+};
+#[cfg(feature = "std")]
+pub use builder::{PasswordPolicy, PasswordPolicyBuilder};
+#[cfg(any(feature = "regex", feature = "serde"))]
+pub use config::ConfigError;
+#[cfg(feature = "std")]
+pub use config::{
+    BlacklistMissingBehavior, DEFAULT_BLACKLIST_PENALTY, DEFAULT_BLACKLIST_SEPARATORS,
+    DEFAULT_MAX_LENGTH, DEFAULT_MIN_UNIQUE_RATIO, DEFAULT_PASSPHRASE_MIN_LENGTH, DEFAULT_PENALTY,
+    DEFAULT_REPEAT_THRESHOLD, DEFAULT_SEQUENTIAL_THRESHOLD, DEFAULT_SPECIAL_CHARS,
+    DEFAULT_UNIQUE_CHAR_BONUSES, EvaluatorConfig, Fingerprinter, ScoreBonuses,
+};
+#[cfg(feature = "std")]
+pub use denylist::{DenylistError, contains_denied_term, init_denylist_from_iter};
+#[cfg(feature = "std")]
+pub use dictionary::{
+    DictionaryError, init_dictionary_from_iter, init_dictionary_from_path,
+    init_locale_dictionary_from_iter, init_locale_dictionary_from_path, is_dictionary_word,
+    is_dictionary_word_for,
+};
+#[cfg(feature = "std")]
+pub use diff::{EvaluationDiff, diff_evaluations};
+#[cfg(feature = "std")]
+pub use evaluator::{
+    DetailedEvaluation, default_section_names, evaluate_bytes, evaluate_bytes_with_config,
+    evaluate_password_strength, evaluate_password_strength_detailed,
+    evaluate_password_strength_pure, evaluate_password_strength_with_config, evaluate_str,
+    evaluate_string, is_acceptable, is_acceptable_with_config, quick_reject, score_from_components,
+    strength_only, strongest_of, try_evaluate_password_strength_with_config,
+};
+#[cfg(feature = "std")]
+pub use history::{
+    HashAlgorithm, HashFn, PasswordHash, evaluate_with_history, evaluate_with_history_with_config,
+};
+pub use keyboard::KeyboardLayout;
+pub use locale::Locale;
+pub use reason::{CharClass, Reason, ReasonCode, Severity};
+#[cfg(feature = "serde")]
+pub use report::to_canonical_json;
+#[cfg(feature = "std")]
+pub use report::{OFFLINE_GUESSES_PER_SECOND, ScoreReport, estimate_entropy_bits, evaluate_report};
+#[cfg(feature = "std")]
+pub use requirements::{PolicySummary, Requirement, RequirementStatus};
+#[cfg(feature = "std")]
+pub use score::{EvaluationExt, ScoreExt, StrengthExt};
+#[cfg(feature = "std")]
+pub use sections::{
+    Section, SectionContext, SectionDescription, SectionFn, SectionResult, describe_sections,
+};
+#[cfg(feature = "std")]
+pub use similarity::{evaluate_against_previous, evaluate_against_previous_with_config};
+pub use stats::{CharClassCounts, CharStats};
+
+#[cfg(feature = "cache")]
+pub use cache::EvaluationCache;
+
+#[cfg(feature = "timing")]
+pub use evaluator::EvaluationTiming;
+
+#[cfg(feature = "metrics")]
+pub use observer::{EvaluationObserver, EvaluationOutcome};
+
+#[cfg(feature = "async")]
+pub use blacklist::{AsyncBlacklistSource, init_blacklist_async, init_blacklist_from_path_async};
+
+#[cfg(feature = "async")]
+pub use evaluator::{evaluate_password_strength_tx, evaluate_password_strength_with_source};
+
+#[cfg(feature = "async")]
+pub use live::{DEFAULT_DEBOUNCE, LiveEvaluator};
 
 #[cfg(feature = "async")]
-pub use evaluator::evaluate_password_strength_tx;
+pub use stream::{
+    EvalEvent, SectionProgress, evaluate_password_strength_stream,
+    evaluate_password_strength_tx_events,
+};
@@ -0,0 +1,149 @@
+//! Debounced evaluation for live typing.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use pwd_types::PasswordEvaluation;
+use secrecy::SecretString;
+use tokio_util::sync::CancellationToken;
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::evaluate_password_strength_with_config;
+
+/// Default pause after the last keystroke before evaluating.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Coalesces rapid inputs and evaluates only once the user pauses.
+///
+/// Every call to [`LiveEvaluator::on_input`] supersedes the previous one:
+/// the older call is cancelled and resolves to `None`, so only the latest
+/// input produces an evaluation.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let live = LiveEvaluator::new(EvaluatorConfig::default(), Duration::from_millis(250));
+///
+/// // On every keystroke:
+/// if let Some(evaluation) = live.on_input(&password).await {
+///     render(evaluation);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LiveEvaluator {
+    config: EvaluatorConfig,
+    debounce: Duration,
+    current: Mutex<CancellationToken>,
+}
+
+impl LiveEvaluator {
+    /// Creates a live evaluator with the given config and debounce duration.
+    pub fn new(config: EvaluatorConfig, debounce: Duration) -> Self {
+        Self {
+            config,
+            debounce,
+            current: Mutex::new(CancellationToken::new()),
+        }
+    }
+
+    /// Returns the debounce duration.
+    pub fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Registers a new input and evaluates it after the debounce period.
+    ///
+    /// Returns `None` if a newer input arrived (or [`LiveEvaluator::cancel`]
+    /// was called) before this evaluation completed.
+    pub async fn on_input(&self, password: &SecretString) -> Option<PasswordEvaluation> {
+        let token = self.supersede();
+
+        tokio::select! {
+            _ = token.cancelled() => return None,
+            _ = tokio::time::sleep(self.debounce) => {}
+        }
+
+        let evaluation =
+            evaluate_password_strength_with_config(password, &self.config, Some(token.clone()));
+        if token.is_cancelled() {
+            return None;
+        }
+        Some(evaluation)
+    }
+
+    /// Cancels the pending evaluation, if any.
+    pub fn cancel(&self) {
+        self.current
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .cancel();
+    }
+
+    /// Cancels the pending evaluation and installs a fresh token for the next one.
+    fn supersede(&self) -> CancellationToken {
+        let token = CancellationToken::new();
+        let mut current = self.current.lock().unwrap_or_else(|e| e.into_inner());
+        current.cancel();
+        *current = token.clone();
+        token
+    }
+}
+
+impl Default for LiveEvaluator {
+    fn default() -> Self {
+        Self::new(EvaluatorConfig::default(), DEFAULT_DEBOUNCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::setup_blacklist;
+    use serial_test::serial;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_live_evaluator_only_last_input_produces_result() {
+        setup_blacklist();
+        let live = Arc::new(LiveEvaluator::new(
+            EvaluatorConfig::default(),
+            Duration::from_millis(50),
+        ));
+
+        let mut handles = Vec::new();
+        for input in ["S", "Su", "Sum", "Summer2024!Xy"] {
+            let live = Arc::clone(&live);
+            handles.push(tokio::spawn(async move {
+                let pwd = SecretString::new(input.to_string().into());
+                live.on_input(&pwd).await
+            }));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        let mut results = Vec::new();
+        for handle in handles {
+            results.push(handle.await.expect("task panicked"));
+        }
+
+        let (last, superseded) = results.split_last().unwrap();
+        assert!(superseded.iter().all(|r| r.is_none()));
+        assert!(last.as_ref().is_some_and(|e| e.score.is_some()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_live_evaluator_single_input_after_pause() {
+        setup_blacklist();
+        let live = LiveEvaluator::new(EvaluatorConfig::default(), Duration::from_millis(10));
+        let pwd = SecretString::new("TestPass123!".to_string().into());
+
+        let evaluation = live.on_input(&pwd).await;
+        assert!(evaluation.is_some());
+    }
+
+    #[test]
+    fn test_live_evaluator_default_debounce() {
+        assert_eq!(LiveEvaluator::default().debounce(), DEFAULT_DEBOUNCE);
+    }
+}
@@ -0,0 +1,96 @@
+//! Locales - per-language defaults for locale-sensitive checks.
+
+use crate::keyboard::KeyboardLayout;
+
+/// Language of the users a deployment serves.
+///
+/// Stored in `EvaluatorConfig::locale`; pass to `EvaluatorConfig::for_locale`
+/// to also get the matching keyboard layout. The dictionary section checks
+/// the word list loaded for the locale with
+/// `init_locale_dictionary_from_path` in addition to the global
+/// dictionary. The blacklist is not split by locale, as leaked-password
+/// lists overlap heavily across languages: merge a language's common
+/// passwords into it with `extend_blacklist_from_path`, using
+/// `Locale::code` to pick the file (e.g.
+/// `format!("blacklist.{}.txt", locale.code())`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Locale {
+    /// English (QWERTY).
+    #[default]
+    English,
+    /// French (AZERTY).
+    French,
+    /// German (QWERTZ).
+    German,
+    /// Spanish (QWERTY).
+    Spanish,
+    /// Italian (QWERTY).
+    Italian,
+}
+
+impl Locale {
+    /// Parses a language tag such as `"fr"` or `"de-CH"` by its primary
+    /// subtag (case-insensitive).
+    ///
+    /// Languages without specific support fall back to `Locale::English`,
+    /// whose defaults (QWERTY) are the most widely used.
+    pub fn from_tag(tag: &str) -> Self {
+        let language = tag.split(['-', '_']).next().unwrap_or("");
+        match language.to_ascii_lowercase().as_str() {
+            "fr" => Locale::French,
+            "de" => Locale::German,
+            "es" => Locale::Spanish,
+            "it" => Locale::Italian,
+            _ => Locale::English,
+        }
+    }
+
+    /// ISO 639-1 code of the language, e.g. `"fr"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::French => "fr",
+            Locale::German => "de",
+            Locale::Spanish => "es",
+            Locale::Italian => "it",
+        }
+    }
+
+    /// Most common keyboard layout for the language.
+    pub fn keyboard_layout(self) -> KeyboardLayout {
+        match self {
+            Locale::French => KeyboardLayout::Azerty,
+            Locale::German => KeyboardLayout::Qwertz,
+            Locale::English | Locale::Spanish | Locale::Italian => KeyboardLayout::Qwerty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_keyboard_layout() {
+        assert_eq!(Locale::French.keyboard_layout(), KeyboardLayout::Azerty);
+        assert_eq!(Locale::German.keyboard_layout(), KeyboardLayout::Qwertz);
+        assert_eq!(Locale::English.keyboard_layout(), KeyboardLayout::Qwerty);
+        assert_eq!(Locale::Italian.keyboard_layout(), KeyboardLayout::Qwerty);
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("fr"), Locale::French);
+        assert_eq!(Locale::from_tag("de-CH"), Locale::German);
+        assert_eq!(Locale::from_tag("ES_es"), Locale::Spanish);
+        // Unsupported languages fall back to English
+        assert_eq!(Locale::from_tag("pt-BR"), Locale::English);
+        assert_eq!(Locale::from_tag(""), Locale::English);
+        assert_eq!(Locale::from_tag("fr-BE").code(), "fr");
+    }
+}
@@ -0,0 +1,48 @@
+//! Unicode normalization helpers
+//!
+//! Passwords and blacklist entries are brought to NFC (as recommended by
+//! RFC 8265 for opaque strings) so that visually identical input compares
+//! and scores the same regardless of how it was composed.
+
+use secrecy::{ExposeSecret, SecretString};
+use std::borrow::Cow;
+use unicode_normalization::{UnicodeNormalization, is_nfc};
+
+/// Returns the NFC form of `s`, borrowing when it is already normalized.
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    if is_nfc(s) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.nfc().collect())
+    }
+}
+
+/// Returns a normalized copy of the password, or `None` if it is already NFC.
+pub fn normalize_secret(password: &SecretString) -> Option<SecretString> {
+    match normalize(password.expose_secret()) {
+        Cow::Borrowed(_) => None,
+        Cow::Owned(s) => Some(SecretString::new(s.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_composes_decomposed_input() {
+        let decomposed = "caf\u{0065}\u{0301}";
+        assert_eq!(normalize(decomposed), "caf\u{00e9}");
+    }
+
+    #[test]
+    fn test_normalize_borrows_nfc_input() {
+        assert!(matches!(normalize("caf\u{00e9}"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_normalize_secret_already_normalized() {
+        let pwd = SecretString::new("Plain123!".to_string().into());
+        assert!(normalize_secret(&pwd).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+//! Evaluation observer - hooks for counting evaluation outcomes.
+//!
+//! Enabled with the `metrics` feature. Set an observer as
+//! `EvaluatorConfig::observer` to feed strength buckets and blacklist hit
+//! rates into your metrics system. The observer never sees the password.
+
+use std::fmt;
+
+use pwd_types::PasswordStrength;
+
+use crate::reason::ReasonCode;
+
+/// Summary of one finished evaluation, passed to `EvaluationObserver`.
+#[derive(Debug)]
+pub struct EvaluationOutcome<'a> {
+    /// Strength bucket of the result (`NotEvaluated` if cancelled).
+    pub strength: PasswordStrength,
+    /// Score of the result, `None` if cancelled.
+    pub score: Option<i64>,
+    /// Codes of the reasons found. Empty for rejected (empty or oversized)
+    /// and cancelled evaluations.
+    pub reasons: &'a [ReasonCode],
+    /// Output of `EvaluatorConfig::fingerprint`, `None` if not configured.
+    pub fingerprint: Option<&'a str>,
+}
+
+impl EvaluationOutcome<'_> {
+    /// Returns `true` if any blacklist check flagged the password.
+    pub fn is_blacklisted(&self) -> bool {
+        self.reasons.iter().any(|code| {
+            matches!(
+                code,
+                ReasonCode::Blacklisted
+                    | ReasonCode::BlacklistedCore
+                    | ReasonCode::TrivialSuffix
+                    | ReasonCode::BlacklistedSeparated
+                    | ReasonCode::BlacklistedSubstring
+                    | ReasonCode::LookAlike
+            )
+        })
+    }
+}
+
+/// Receives the outcome of every evaluation run with a config that sets it.
+///
+/// Called once at the end of each evaluation on the evaluating thread, so
+/// implementations should be cheap (e.g. increment atomic counters).
+pub trait EvaluationObserver: fmt::Debug + Send + Sync {
+    /// Records one finished evaluation.
+    fn on_evaluation(&self, outcome: &EvaluationOutcome<'_>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EvaluatorConfig;
+    use crate::evaluator::evaluate_password_strength_with_config;
+    use secrecy::SecretString;
+    use serial_test::serial;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<(String, bool)>>);
+
+    impl EvaluationObserver for Recorder {
+        fn on_evaluation(&self, outcome: &EvaluationOutcome<'_>) {
+            let strength = format!("{:?}", outcome.strength);
+            self.0
+                .lock()
+                .unwrap()
+                .push((strength, outcome.is_blacklisted()));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_observer_sees_each_evaluation() {
+        crate::blacklist::reset_blacklist_for_testing();
+        crate::blacklist::init_blacklist_from_iter(["password"]).unwrap();
+        let recorder = Arc::new(Recorder::default());
+        let config = EvaluatorConfig {
+            observer: Some(recorder.clone()),
+            ..Default::default()
+        };
+
+        let batch = ["password", "abc", "Pass1234word!", "Kx7#mQ2!vR9$wL4@"];
+        let mut expected = Vec::new();
+        for pwd in batch {
+            let pwd = SecretString::new(pwd.to_string().into());
+            #[cfg(feature = "async")]
+            let evaluation = evaluate_password_strength_with_config(&pwd, &config, None);
+            #[cfg(not(feature = "async"))]
+            let evaluation = evaluate_password_strength_with_config(&pwd, &config);
+            expected.push(format!("{:?}", evaluation.strength()));
+        }
+
+        let seen = recorder.0.lock().unwrap();
+        let strengths: Vec<_> = seen.iter().map(|(s, _)| s.clone()).collect();
+        assert_eq!(strengths, expected);
+        assert_eq!(
+            seen.iter().filter(|(_, blacklisted)| *blacklisted).count(),
+            1
+        );
+        assert!(seen[0].1);
+
+        crate::blacklist::reset_blacklist_for_testing();
+    }
+
+    #[derive(Debug, Default)]
+    struct FingerprintRecorder(Mutex<Vec<Option<String>>>);
+
+    impl EvaluationObserver for FingerprintRecorder {
+        fn on_evaluation(&self, outcome: &EvaluationOutcome<'_>) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(outcome.fingerprint.map(str::to_string));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_observer_receives_fingerprint_only() {
+        crate::blacklist::reset_blacklist_for_testing();
+        crate::blacklist::init_blacklist_from_iter(["password"]).unwrap();
+        let recorder = Arc::new(FingerprintRecorder::default());
+        let config = EvaluatorConfig {
+            fingerprint: Some(crate::config::Fingerprinter::new(|p| {
+                format!("len:{}", p.len())
+            })),
+            observer: Some(recorder.clone()),
+            ..Default::default()
+        };
+
+        let pwd = SecretString::new("hunter2!".to_string().into());
+        #[cfg(feature = "async")]
+        evaluate_password_strength_with_config(&pwd, &config, None);
+        #[cfg(not(feature = "async"))]
+        evaluate_password_strength_with_config(&pwd, &config);
+
+        assert_eq!(*recorder.0.lock().unwrap(), [Some("len:8".to_string())]);
+
+        crate::blacklist::reset_blacklist_for_testing();
+    }
+}
@@ -0,0 +1,307 @@
+//! Policy files - builds an `EvaluatorConfig` from TOML or JSON.
+//!
+//! Lets operators keep the password policy in a versioned file instead of
+//! code. Every key is optional and missing keys keep their defaults, so a
+//! policy only lists what it changes. Names are in snake case: sections
+//! as returned by `Section::name`, reason codes (`too_short`,
+//! `blacklisted`), character classes (`uppercase`, `lowercase`, `digit`,
+//! `special`), keyboard layouts (`qwerty`, `azerty`) and locales
+//! (`english`, `french`). A `locale` also selects its keyboard layout
+//! unless `keyboard_layout` is given.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::config::{BlacklistMissingBehavior, ConfigError, EvaluatorConfig, ScoreBonuses};
+use crate::keyboard::KeyboardLayout;
+use crate::locale::Locale;
+use crate::reason::{CharClass, ReasonCode};
+use crate::sections::Section;
+
+/// Contents of a policy file; see the `EvaluatorConfig` fields of the
+/// same names.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PolicyFile {
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    length_advisory_band: Option<usize>,
+    required_classes: Option<Vec<CharClass>>,
+    special_chars: Option<String>,
+    min_unique_ratio: Option<f64>,
+    repeat_threshold: Option<usize>,
+    sequential_threshold: Option<usize>,
+    keyboard_layout: Option<KeyboardLayout>,
+    locale: Option<Locale>,
+    unique_char_bonuses: Option<Vec<(usize, i64)>>,
+    bonuses: Option<ScoreBonuses>,
+    passphrase_mode: Option<bool>,
+    passphrase_min_length: Option<usize>,
+    penalties: HashMap<ReasonCode, i64>,
+    sections: Option<Vec<Section>>,
+    fail_fast: Option<bool>,
+    min_score: Option<i64>,
+    blacklist_path: Option<PathBuf>,
+    blacklist_substring_min_length: Option<usize>,
+    blacklist_strip_affixes: Option<bool>,
+    blacklist_ignored_separators: Option<String>,
+    blacklist_missing: Option<BlacklistMissingBehavior>,
+}
+
+impl PolicyFile {
+    /// Applies the policy on top of the default configuration.
+    ///
+    /// Penalties are merged into the default ones, so listing one code
+    /// does not reset the others.
+    fn into_config(self) -> EvaluatorConfig {
+        let mut config = EvaluatorConfig::default();
+        if let Some(min_length) = self.min_length {
+            config.min_length = min_length;
+        }
+        if let Some(max_length) = self.max_length {
+            config.max_length = max_length;
+        }
+        if let Some(band) = self.length_advisory_band {
+            config.length_advisory_band = band;
+        }
+        if let Some(classes) = self.required_classes {
+            config.required_classes = classes;
+        }
+        if let Some(special_chars) = self.special_chars {
+            config.special_chars = special_chars;
+        }
+        if let Some(ratio) = self.min_unique_ratio {
+            config.min_unique_ratio = ratio;
+        }
+        if let Some(threshold) = self.repeat_threshold {
+            config.repeat_threshold = threshold;
+        }
+        if let Some(threshold) = self.sequential_threshold {
+            config.sequential_threshold = threshold;
+        }
+        if let Some(locale) = self.locale {
+            config.locale = locale;
+            config.keyboard_layout = locale.keyboard_layout();
+        }
+        if let Some(layout) = self.keyboard_layout {
+            config.keyboard_layout = layout;
+        }
+        if let Some(bonuses) = self.unique_char_bonuses {
+            config.unique_char_bonuses = bonuses;
+        }
+        if let Some(bonuses) = self.bonuses {
+            config.bonuses = bonuses;
+        }
+        if let Some(passphrase_mode) = self.passphrase_mode {
+            config.passphrase_mode = passphrase_mode;
+        }
+        if let Some(length) = self.passphrase_min_length {
+            config.passphrase_min_length = length;
+        }
+        config.penalties.extend(self.penalties);
+        if let Some(sections) = self.sections {
+            config.sections = sections;
+        }
+        if let Some(fail_fast) = self.fail_fast {
+            config.fail_fast = fail_fast;
+        }
+        if let Some(min_score) = self.min_score {
+            config.min_score = Some(min_score);
+        }
+        if let Some(path) = self.blacklist_path {
+            config.blacklist_path = Some(path);
+        }
+        if let Some(length) = self.blacklist_substring_min_length {
+            config.blacklist_substring_min_length = Some(length);
+        }
+        if let Some(strip) = self.blacklist_strip_affixes {
+            config.blacklist_strip_affixes = strip;
+        }
+        if let Some(separators) = self.blacklist_ignored_separators {
+            config.blacklist_ignored_separators = Some(separators);
+        }
+        if let Some(behavior) = self.blacklist_missing {
+            config.blacklist_missing = behavior;
+        }
+        config
+    }
+}
+
+impl EvaluatorConfig {
+    /// Parses a TOML policy (`serde` feature only).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidToml` for malformed TOML, unknown
+    /// keys or names, and values of the wrong type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pwd_strength::{EvaluatorConfig, ReasonCode};
+    ///
+    /// let config = EvaluatorConfig::from_toml_str(r#"
+    ///     min_length = 12
+    ///     required_classes = ["lowercase", "digit"]
+    ///     blacklist_path = "/etc/pwd-strength/blacklist.txt"
+    ///
+    ///     [penalties]
+    ///     sequential = 25
+    /// "#).expect("valid policy");
+    /// assert_eq!(config.min_length, 12);
+    /// assert_eq!(config.penalty(ReasonCode::Sequential), 25);
+    /// ```
+    pub fn from_toml_str(policy: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str::<PolicyFile>(policy)?.into_config())
+    }
+
+    /// Parses a JSON policy with the keys of `from_toml_str` (`serde`
+    /// feature only).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidJson` for malformed JSON, unknown
+    /// keys or names, and values of the wrong type.
+    pub fn from_json_str(policy: &str) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_str::<PolicyFile>(policy)?.into_config())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_TOML: &str = r#"
+        # Internal accounts policy
+        min_length = 12
+        required_classes = ["uppercase", "lowercase", "digit"]
+        special_chars = "!@#"
+        repeat_threshold = 2
+        sequential_threshold = 3
+        keyboard_layout = "azerty"
+        sections = ["length", "variety", "pattern", "blacklist", "dictionary"]
+        min_score = 70
+        blacklist_path = "/etc/pwd-strength/blacklist.txt"
+        blacklist_strip_affixes = true
+
+        [penalties]
+        sequential = 25
+        too_short = 50
+    "#;
+
+    fn hand_built() -> EvaluatorConfig {
+        EvaluatorConfig {
+            min_length: 12,
+            required_classes: vec![CharClass::Uppercase, CharClass::Lowercase, CharClass::Digit],
+            special_chars: "!@#".to_string(),
+            repeat_threshold: 2,
+            sequential_threshold: 3,
+            keyboard_layout: KeyboardLayout::Azerty,
+            sections: vec![
+                Section::Length,
+                Section::Variety,
+                Section::Pattern,
+                Section::Blacklist,
+                Section::Dictionary,
+            ],
+            min_score: Some(70),
+            blacklist_path: Some(PathBuf::from("/etc/pwd-strength/blacklist.txt")),
+            blacklist_strip_affixes: true,
+            ..Default::default()
+        }
+        .with_penalty(ReasonCode::Sequential, 25)
+        .with_penalty(ReasonCode::TooShort, 50)
+    }
+
+    #[test]
+    fn test_from_toml_str_matches_hand_built_config() {
+        let config = EvaluatorConfig::from_toml_str(SAMPLE_TOML).unwrap();
+        assert_eq!(config, hand_built());
+        // Penalties not listed keep their defaults
+        assert_eq!(
+            config.penalty(ReasonCode::Blacklisted),
+            crate::config::DEFAULT_BLACKLIST_PENALTY
+        );
+    }
+
+    #[test]
+    fn test_from_json_str_matches_hand_built_config() {
+        let json = r#"{
+            "min_length": 12,
+            "required_classes": ["uppercase", "lowercase", "digit"],
+            "special_chars": "!@#",
+            "repeat_threshold": 2,
+            "sequential_threshold": 3,
+            "keyboard_layout": "azerty",
+            "sections": ["length", "variety", "pattern", "blacklist", "dictionary"],
+            "min_score": 70,
+            "blacklist_path": "/etc/pwd-strength/blacklist.txt",
+            "blacklist_strip_affixes": true,
+            "penalties": { "sequential": 25, "too_short": 50 }
+        }"#;
+        assert_eq!(EvaluatorConfig::from_json_str(json).unwrap(), hand_built());
+    }
+
+    #[test]
+    fn test_bonuses_table_overrides_listed_components() {
+        let config =
+            EvaluatorConfig::from_toml_str("[bonuses]\nlength = false\nvariety = false").unwrap();
+        assert_eq!(
+            config.bonuses,
+            ScoreBonuses {
+                length: false,
+                variety: false,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_locale_selects_keyboard_layout_unless_given() {
+        let config = EvaluatorConfig::from_toml_str("locale = \"german\"").unwrap();
+        assert_eq!(config, EvaluatorConfig::for_locale(Locale::German));
+
+        let policy = "locale = \"french\"\nkeyboard_layout = \"qwerty\"";
+        let config = EvaluatorConfig::from_toml_str(policy).unwrap();
+        assert_eq!(
+            (config.locale, config.keyboard_layout),
+            (Locale::French, KeyboardLayout::Qwerty)
+        );
+    }
+
+    #[test]
+    fn test_empty_policy_is_default() {
+        assert_eq!(
+            EvaluatorConfig::from_toml_str("").unwrap(),
+            EvaluatorConfig::default()
+        );
+        assert_eq!(
+            EvaluatorConfig::from_json_str("{}").unwrap(),
+            EvaluatorConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_invalid_policies_are_rejected() {
+        for toml in [
+            "min_lenght = 12",
+            "required_classes = [\"symbols\"]",
+            "min_length = \"twelve\"",
+            "[penalties]\nnot_a_code = 5",
+        ] {
+            assert!(
+                matches!(
+                    EvaluatorConfig::from_toml_str(toml),
+                    Err(ConfigError::InvalidToml(_))
+                ),
+                "{toml}"
+            );
+        }
+        assert!(matches!(
+            EvaluatorConfig::from_json_str(r#"{"sections": ["spelling"]}"#),
+            Err(ConfigError::InvalidJson(_))
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+//! Structured reasons - machine-readable codes behind each reason message.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Identifies why a password lost points.
+///
+/// Used as the key for per-reason penalties in `EvaluatorConfig`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ReasonCode {
+    /// Password is in the blacklist.
+    Blacklisted,
+    /// Password is a blacklisted password wrapped in digits or symbols.
+    BlacklistedCore,
+    /// Password is a blacklisted password followed by up to two digits
+    /// and one symbol (e.g. `"password1!"`).
+    TrivialSuffix,
+    /// Password is a blacklisted password with separators inserted
+    /// (e.g. `"pass-word"`).
+    BlacklistedSeparated,
+    /// Password contains a blacklisted password as a substring.
+    BlacklistedSubstring,
+    /// Password is a blacklisted password spelled with look-alike characters.
+    LookAlike,
+    /// No blacklist was loaded, so common passwords were not checked
+    /// (advisory; see `BlacklistMissingBehavior::Warn`).
+    BlacklistNotLoaded,
+    /// Password contains a term from the denylist.
+    DeniedTerm,
+    /// Password mixes letters from several scripts.
+    MixedScript,
+    /// Password is a single dictionary word with trivial adornment.
+    DictionaryWord,
+    /// Password is a dictionary word with digits or symbols standing in
+    /// for letters (e.g. `"P@$$w0rd"`).
+    ObfuscatedDictionaryWord,
+    /// Password is shorter than the minimum length.
+    TooShort,
+    /// Password length is only just above the minimum (advisory).
+    MarginalLength,
+    /// Password lacks one or more character types.
+    MissingCharacterTypes,
+    /// Password repeats the same character at least
+    /// `EvaluatorConfig::repeat_threshold` times in a row (three by default).
+    Repetitive,
+    /// Password contains a run of consecutive characters.
+    Sequential,
+    /// Password contains a run of neighbouring keys (e.g. `"asdf"`).
+    KeyboardWalk,
+    /// Password reads the same forwards and backwards.
+    Palindrome,
+    /// Password is dominated by a phone-number-like run of 10 or 11 digits.
+    PhoneNumber,
+    /// Password is dominated by a date-like run of 6 or 8 digits (`"01011990"`).
+    Date,
+    /// Password has too few unique characters for its length.
+    LowUniqueness,
+    /// Password matches a configured deny pattern.
+    ForbiddenPattern,
+    /// Password is too similar to the previous password.
+    TooSimilar,
+    /// Password scored below `EvaluatorConfig::min_score`.
+    BelowMinScore,
+    /// A section failed unexpectedly.
+    Error,
+}
+
+impl ReasonCode {
+    /// How serious a reason with this code is.
+    pub fn severity(self) -> Severity {
+        match self {
+            ReasonCode::MarginalLength | ReasonCode::BlacklistNotLoaded => Severity::Info,
+            ReasonCode::MixedScript | ReasonCode::Palindrome => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// How serious a reason is.
+///
+/// `Error` and `Warning` reasons cost points; `Info` reasons are advice
+/// only and never affect the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// Advice, e.g. "consider a longer password".
+    Info,
+    /// Suspicious but not necessarily weak.
+    Warning,
+    /// The password fails a check.
+    Error,
+}
+
+/// A reason code together with its human-readable message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reason {
+    /// Machine-readable code.
+    pub code: ReasonCode,
+    /// Message reported in `PasswordEvaluation::reasons`.
+    pub message: String,
+    /// Character classes the password lacks. Only non-empty for
+    /// `ReasonCode::MissingCharacterTypes`.
+    pub missing_classes: Vec<CharClass>,
+}
+
+/// A required character class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CharClass {
+    /// Uppercase letters.
+    Uppercase,
+    /// Lowercase letters.
+    Lowercase,
+    /// ASCII digits.
+    Digit,
+    /// Characters of `EvaluatorConfig::special_chars`; whitespace and
+    /// unlisted symbols do not count.
+    Special,
+}
+
+impl CharClass {
+    /// Name used in reason messages, e.g. `"numbers"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            CharClass::Uppercase => "uppercase",
+            CharClass::Lowercase => "lowercase",
+            CharClass::Digit => "numbers",
+            CharClass::Special => "special characters",
+        }
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Reason {
+    /// Creates a reason.
+    pub fn new(code: ReasonCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            missing_classes: Vec::new(),
+        }
+    }
+
+    /// Creates a `MissingCharacterTypes` reason listing `classes`.
+    ///
+    /// The message reads `"Missing: uppercase, numbers"`.
+    pub fn missing(classes: Vec<CharClass>) -> Self {
+        let names: Vec<_> = classes.iter().map(|c| c.name()).collect();
+        Self {
+            code: ReasonCode::MissingCharacterTypes,
+            message: format!("Missing: {}", names.join(", ")),
+            missing_classes: classes,
+        }
+    }
+
+    /// Severity of the reason's code.
+    pub fn severity(&self) -> Severity {
+        self.code.severity()
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_of_codes() {
+        assert_eq!(ReasonCode::Blacklisted.severity(), Severity::Error);
+        assert_eq!(ReasonCode::MixedScript.severity(), Severity::Warning);
+        assert_eq!(ReasonCode::MarginalLength.severity(), Severity::Info);
+        assert_eq!(
+            Reason::new(ReasonCode::TooShort, "short").severity(),
+            Severity::Error
+        );
+        assert!(Severity::Info < Severity::Warning && Severity::Warning < Severity::Error);
+    }
+
+    #[test]
+    fn test_missing_reason_message() {
+        let reason = Reason::missing(vec![CharClass::Uppercase, CharClass::Digit]);
+        assert_eq!(reason.code, ReasonCode::MissingCharacterTypes);
+        assert_eq!(reason.to_string(), "Missing: uppercase, numbers");
+        assert_eq!(
+            reason.missing_classes,
+            [CharClass::Uppercase, CharClass::Digit]
+        );
+        assert!(
+            Reason::new(ReasonCode::TooShort, "short")
+                .missing_classes
+                .is_empty()
+        );
+    }
+}
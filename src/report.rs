@@ -0,0 +1,241 @@
+//! Score report - everything a frontend needs from one evaluation.
+
+#[cfg(feature = "serde")]
+use pwd_types::PasswordEvaluation;
+use pwd_types::PasswordStrength;
+use secrecy::{ExposeSecret, SecretString};
+use zeroize::Zeroizing;
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::evaluate_with_stats;
+use crate::stats::{CharClassCounts, CharStats};
+
+/// Guesses per second assumed by `ScoreReport::crack_time_seconds`
+/// (an offline attack on a fast hash).
+pub const OFFLINE_GUESSES_PER_SECOND: f64 = 1e10;
+
+/// Score, strength, entropy estimate and character counts of a password.
+#[derive(Debug, PartialEq)]
+pub struct ScoreReport {
+    /// Final score, `None` if the password was not evaluated.
+    pub score: Option<i64>,
+    /// Strength level derived from `score`.
+    pub strength: PasswordStrength,
+    /// Estimated entropy in bits (see `CharStats::entropy_bits`).
+    pub entropy_bits: f64,
+    /// Average time in seconds to guess the password by brute force at
+    /// `OFFLINE_GUESSES_PER_SECOND`.
+    pub crack_time_seconds: f64,
+    /// Characters per class.
+    pub char_counts: CharClassCounts,
+    /// Reasons the password lost points.
+    pub reasons: Vec<String>,
+}
+
+/// Evaluates `password` and aggregates the result into a `ScoreReport`.
+///
+/// Passwords rejected for length report zero entropy and character counts.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let report = pwd_strength::evaluate_report(&password, &EvaluatorConfig::default());
+/// println!("{:?}: {:.0} bits", report.strength, report.entropy_bits);
+/// ```
+pub fn evaluate_report(password: &SecretString, config: &EvaluatorConfig) -> ScoreReport {
+    let (evaluation, stats) = evaluate_with_stats(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    );
+    let entropy_bits = stats.entropy_bits();
+
+    ScoreReport {
+        score: evaluation.score.as_ref().map(|s| s.value()),
+        strength: evaluation.strength(),
+        entropy_bits,
+        crack_time_seconds: crack_time_seconds(entropy_bits),
+        char_counts: stats.counts,
+        reasons: evaluation.reasons,
+    }
+}
+
+/// Estimates the entropy of `password` in bits, without the evaluation
+/// pipeline.
+///
+/// Returns the smaller of the pool-size estimate
+/// (`CharStats::entropy_bits`) and the Shannon entropy of the character
+/// frequencies times the length, so repeated characters add little:
+/// `"aaaaaaaa"` has zero bits. The estimate never decreases when
+/// characters are appended. No blacklist or section is consulted and the
+/// length is not limited; bound untrusted input first.
+pub fn estimate_entropy_bits(password: &SecretString) -> f64 {
+    let pwd = password.expose_secret();
+    let pool_bits = CharStats::compute(pwd).entropy_bits();
+
+    let mut chars: Zeroizing<Vec<char>> = Zeroizing::new(pwd.chars().collect());
+    chars.sort_unstable();
+    let len = chars.len() as f64;
+    let shannon_bits: f64 = chars
+        .chunk_by(|a, b| a == b)
+        .map(|run| {
+            let count = run.len() as f64;
+            count * (len / count).log2()
+        })
+        .sum();
+
+    pool_bits.min(shannon_bits)
+}
+
+/// Average brute-force time for a password of `entropy_bits`: half the
+/// search space at `OFFLINE_GUESSES_PER_SECOND`.
+fn crack_time_seconds(entropy_bits: f64) -> f64 {
+    if entropy_bits <= 0.0 {
+        return 0.0;
+    }
+    2f64.powf(entropy_bits - 1.0) / OFFLINE_GUESSES_PER_SECOND
+}
+
+/// Serializes `evaluation` as compact JSON with a fixed layout (`serde`
+/// feature only), e.g. for snapshot tests across versions.
+///
+/// Keys are in alphabetical order (`reasons`, `score`, `strength`) and
+/// reasons keep the evaluation order, which follows the sections, so equal
+/// evaluations always give the same bytes. Unscored evaluations
+/// (cancelled or oversized input) have `"score":null`.
+#[cfg(feature = "serde")]
+pub fn to_canonical_json(evaluation: &PasswordEvaluation) -> String {
+    let reasons = serde_json::to_string(&evaluation.reasons).expect("strings serialize");
+    let score = serde_json::to_string(&evaluation.score.as_ref().map(|s| s.value()))
+        .expect("integers serialize");
+    let strength =
+        serde_json::to_string(&format!("{:?}", evaluation.strength())).expect("strings serialize");
+    format!(
+        "{{\"reasons\":{},\"score\":{},\"strength\":{}}}",
+        reasons, score, strength
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::setup_blacklist;
+    use serial_test::serial;
+
+    fn entropy(password: &str) -> f64 {
+        estimate_entropy_bits(&SecretString::new(password.to_string().into()))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[serial]
+    fn test_to_canonical_json_is_deterministic() {
+        setup_blacklist();
+        let pwd = SecretString::new("password1".to_string().into());
+        let evaluate = || {
+            crate::evaluator::evaluate_password_strength_with_config(
+                &pwd,
+                &EvaluatorConfig::default(),
+                #[cfg(feature = "async")]
+                None,
+            )
+        };
+
+        let first = to_canonical_json(&evaluate());
+        assert_eq!(first, to_canonical_json(&evaluate()));
+        assert!(first.starts_with(
+            r#"{"reasons":["Password is a common password with trivial modification","#
+        ));
+        assert!(first.ends_with(r#""strength":"WEAK"}"#));
+
+        let unscored = PasswordEvaluation {
+            score: None,
+            reasons: vec!["Say \"hi\"".to_string()],
+        };
+        assert_eq!(
+            to_canonical_json(&unscored),
+            r#"{"reasons":["Say \"hi\""],"score":null,"strength":"NotEvaluated"}"#
+        );
+    }
+
+    #[test]
+    fn test_estimate_entropy_bits_repeated_vs_random() {
+        assert_eq!(entropy(""), 0.0);
+        assert_eq!(entropy("aaaaaaaaaaaa"), 0.0);
+        let repeated = entropy("abababababab");
+        let random = entropy("Kx7#mQ2!vR9$");
+        assert!((repeated - 12.0).abs() < 1e-9, "{repeated}");
+        assert!(random > 40.0, "{random}");
+        // Bounded by the pool-size estimate
+        assert!(random <= CharStats::compute("Kx7#mQ2!vR9$").entropy_bits());
+    }
+
+    #[test]
+    fn test_estimate_entropy_bits_grows_with_length() {
+        let password = "Kx7#mQ2!vR9$aaaaKx7#";
+        let mut previous = 0.0;
+        for end in 1..=password.len() {
+            let bits = entropy(&password[..end]);
+            assert!(
+                bits >= previous,
+                "{} bits at {} chars, {} before",
+                bits,
+                end,
+                previous
+            );
+            previous = bits;
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_report_strong_password_is_consistent() {
+        setup_blacklist();
+        let pwd = SecretString::new("Kx7#mQ2!vR9zLp4&".to_string().into());
+
+        #[cfg(feature = "async")]
+        let evaluation = crate::evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let evaluation = crate::evaluate_password_strength(&pwd);
+
+        let report = evaluate_report(&pwd, &EvaluatorConfig::default());
+
+        assert_eq!(report.score, evaluation.score.as_ref().map(|s| s.value()));
+        assert_eq!(report.strength, evaluation.strength());
+        assert!(report.score.is_some_and(|s| s >= 70));
+        assert_eq!(report.reasons, evaluation.reasons);
+
+        let counts = report.char_counts;
+        assert_eq!(
+            counts.upper + counts.lower + counts.digit + counts.special + counts.other,
+            16
+        );
+        assert_eq!(counts.variety(), 4);
+
+        // 16 characters from the full 95-character printable pool
+        assert!((report.entropy_bits - 16.0 * 95f64.log2()).abs() < 1e-9);
+        assert_eq!(
+            report.crack_time_seconds,
+            crack_time_seconds(report.entropy_bits)
+        );
+        assert!(report.crack_time_seconds > 1e10);
+    }
+
+    #[test]
+    #[serial]
+    fn test_report_oversized_password() {
+        setup_blacklist();
+        let config = EvaluatorConfig {
+            max_length: 8,
+            ..Default::default()
+        };
+        let pwd = SecretString::new("a".repeat(9).into());
+        let report = evaluate_report(&pwd, &config);
+        assert_eq!(report.score, None);
+        assert_eq!(report.entropy_bits, 0.0);
+        assert_eq!(report.crack_time_seconds, 0.0);
+        assert_eq!(report.char_counts, CharClassCounts::default());
+    }
+}
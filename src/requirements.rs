@@ -0,0 +1,146 @@
+//! Requirement checklist - which checks a password passed and failed.
+//!
+//! Unlike the reasons, which only list failures, the checklist has an
+//! entry for every check that ran, for UIs showing "✓ 8+ characters,
+//! ✗ has a symbol". `PolicySummary` describes the policy itself, so the
+//! hints can be rendered before the user has typed anything.
+
+use crate::config::EvaluatorConfig;
+use crate::reason::{CharClass, Severity};
+use crate::sections::{Section, SectionResult};
+
+/// A single policy requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// The password contains a character of this class. The variety
+    /// section reports one such requirement per class.
+    CharClass(CharClass),
+    /// The section with this name found no problem.
+    Section(&'static str),
+}
+
+/// Whether a password satisfies a requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequirementStatus {
+    /// The requirement.
+    pub requirement: Requirement,
+    /// `true` if the password satisfies it.
+    pub satisfied: bool,
+}
+
+/// Effective policy of an evaluation, for UIs rendering the requirements
+/// without parsing reason messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicySummary {
+    /// Minimum length in bytes, `None` if the length section is disabled.
+    pub min_length: Option<usize>,
+    /// Character classes the password must contain. Empty if the variety
+    /// section is disabled.
+    pub required_classes: Vec<CharClass>,
+    /// `true` if the blacklist section ran against a blacklist: either
+    /// `EvaluatorConfig::blacklist_source` or the loaded global blacklist.
+    pub blacklist_consulted: bool,
+}
+
+/// Returns the requirements checked by the section `name` and whether
+/// `result` satisfies them. The variety section checks one requirement per
+/// class of `required_classes`.
+///
+/// Advisory (`Severity::Info`) reasons do not fail a requirement; a
+/// section error fails all of its requirements.
+pub(crate) fn statuses(
+    name: &'static str,
+    result: &SectionResult,
+    required_classes: &[CharClass],
+) -> Vec<RequirementStatus> {
+    if name == Section::Variety.name() {
+        return required_classes
+            .iter()
+            .map(|&class| RequirementStatus {
+                requirement: Requirement::CharClass(class),
+                satisfied: match result {
+                    Ok(Some(reason)) => !reason.missing_classes.contains(&class),
+                    Ok(None) => true,
+                    Err(()) => false,
+                },
+            })
+            .collect();
+    }
+    let satisfied = match result {
+        Ok(Some(reason)) => reason.severity() == Severity::Info,
+        Ok(None) => true,
+        Err(()) => false,
+    };
+    vec![RequirementStatus {
+        requirement: Requirement::Section(name),
+        satisfied,
+    }]
+}
+
+/// Summarizes the policy of `config` for an evaluation whose sections
+/// produced `requirements`.
+pub(crate) fn summary(
+    config: &EvaluatorConfig,
+    requirements: &[RequirementStatus],
+) -> PolicySummary {
+    let enabled = |section| config.sections.contains(&section);
+    let blacklist_ran = requirements
+        .iter()
+        .any(|s| s.requirement == Requirement::Section(Section::Blacklist.name()));
+    PolicySummary {
+        min_length: enabled(Section::Length).then_some(config.min_length),
+        required_classes: if enabled(Section::Variety) {
+            config.required_classes.clone()
+        } else {
+            Vec::new()
+        },
+        blacklist_consulted: blacklist_ran && !config.blacklist_unavailable(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ALL_CHAR_CLASSES;
+    use crate::reason::{Reason, ReasonCode};
+
+    #[test]
+    fn test_statuses_variety_lists_every_class() {
+        let result = Ok(Some(Reason::missing(vec![CharClass::Digit])));
+        let statuses = statuses("variety", &result, &ALL_CHAR_CLASSES);
+        assert_eq!(statuses.len(), 4);
+        for status in statuses {
+            let missing = status.requirement == Requirement::CharClass(CharClass::Digit);
+            assert_eq!(status.satisfied, !missing);
+        }
+
+        let only_digits = super::statuses("variety", &result, &[CharClass::Digit]);
+        assert_eq!(
+            only_digits,
+            [RequirementStatus {
+                requirement: Requirement::CharClass(CharClass::Digit),
+                satisfied: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_statuses_section_outcomes() {
+        let pass: SectionResult = Ok(None);
+        let advisory = Ok(Some(Reason::new(
+            ReasonCode::MarginalLength,
+            "consider more",
+        )));
+        let fail = Ok(Some(Reason::new(ReasonCode::TooShort, "too short")));
+        let error: SectionResult = Err(());
+        let satisfied: Vec<_> = [pass, advisory, fail, error]
+            .iter()
+            .map(|r| statuses("length", r, &ALL_CHAR_CLASSES)[0].satisfied)
+            .collect();
+        assert_eq!(satisfied, [true, true, false, false]);
+        assert_eq!(
+            statuses("length", &Ok(None), &ALL_CHAR_CLASSES)[0].requirement,
+            Requirement::Section("length")
+        );
+    }
+}
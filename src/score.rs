@@ -0,0 +1,174 @@
+//! Score helpers - comparisons and conversions for `PasswordScore`.
+//!
+//! `PasswordScore` lives in pwd-types, so `PartialOrd`/`Ord` cannot be
+//! implemented here; `ScoreExt` provides the equivalent as methods.
+//! `EvaluationExt` and `StrengthExt` add `fraction` for progress bars.
+//!
+//! `PasswordScore::new` stores any `i64` as is. Every score returned by
+//! this crate is built with `ScoreExt::new_clamped` instead, so its
+//! `value()` is always in `0..=100`.
+
+use std::cmp::Ordering;
+
+use pwd_types::{PasswordEvaluation, PasswordScore, PasswordStrength};
+
+use crate::core::MAX_SCORE;
+
+/// Convenience methods on `PasswordScore`.
+pub trait ScoreExt {
+    /// Creates a score clamped to `0..=100`.
+    fn new_clamped(value: i64) -> Self
+    where
+        Self: Sized;
+
+    /// Compares two scores by value.
+    ///
+    /// Stands in for `Ord` until pwd-types derives it.
+    fn compare(&self, other: &PasswordScore) -> Ordering;
+
+    /// Score as a percentage, clamped to `0..=100`.
+    fn percent(&self) -> u8;
+
+    /// Returns `true` if the score is at least `threshold`.
+    fn meets(&self, threshold: i64) -> bool;
+}
+
+impl ScoreExt for PasswordScore {
+    fn new_clamped(value: i64) -> Self {
+        PasswordScore::new(value.clamp(0, MAX_SCORE))
+    }
+
+    fn compare(&self, other: &PasswordScore) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+
+    fn percent(&self) -> u8 {
+        self.value().clamp(0, 100) as u8
+    }
+
+    fn meets(&self, threshold: i64) -> bool {
+        self.value() >= threshold
+    }
+}
+
+/// Convenience methods on `PasswordEvaluation`.
+pub trait EvaluationExt {
+    /// Score as a fraction in `0.0..=1.0`, e.g. for a progress bar width.
+    ///
+    /// Unscored (cancelled) evaluations return `0.0`.
+    fn fraction(&self) -> f64;
+}
+
+impl EvaluationExt for PasswordEvaluation {
+    fn fraction(&self) -> f64 {
+        self.score
+            .as_ref()
+            .map_or(0.0, |s| f64::from(s.percent()) / 100.0)
+    }
+}
+
+/// Convenience methods on `PasswordStrength`.
+pub trait StrengthExt {
+    /// Representative fraction in `0.0..=1.0` for the tier: the midpoint
+    /// of its score range, or `0.0` for `NotEvaluated`.
+    fn fraction(&self) -> f64;
+}
+
+impl StrengthExt for PasswordStrength {
+    fn fraction(&self) -> f64 {
+        match self {
+            PasswordStrength::NotEvaluated => 0.0,
+            PasswordStrength::WEAK => 0.25,
+            PasswordStrength::MEDIUM => 0.6,
+            PasswordStrength::STRONG => 0.775,
+            PasswordStrength::EPIC => 0.9,
+            PasswordStrength::GOD => 0.98,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_orders_by_value() {
+        let (low, high) = (PasswordScore::new(40), PasswordScore::new(75));
+        assert_eq!(low.compare(&high), Ordering::Less);
+        assert_eq!(high.compare(&low), Ordering::Greater);
+        assert_eq!(low.compare(&PasswordScore::new(40)), Ordering::Equal);
+
+        let mut scores = [
+            PasswordScore::new(90),
+            PasswordScore::new(-5),
+            PasswordScore::new(50),
+        ];
+        scores.sort_by(|a, b| a.compare(b));
+        assert_eq!(scores.map(|s| s.value()), [-5, 50, 90]);
+    }
+
+    #[test]
+    fn test_new_clamped_keeps_value_in_range() {
+        assert_eq!(PasswordScore::new_clamped(500).value(), 100);
+        assert_eq!(PasswordScore::new_clamped(-50).value(), 0);
+        assert_eq!(PasswordScore::new_clamped(i64::MIN).value(), 0);
+        assert_eq!(PasswordScore::new_clamped(42).value(), 42);
+        assert_eq!(PasswordScore::new_clamped(500).percent(), 100);
+    }
+
+    #[test]
+    fn test_percent_clamps() {
+        assert_eq!(PasswordScore::new(-1).percent(), 0);
+        assert_eq!(PasswordScore::new(0).percent(), 0);
+        assert_eq!(PasswordScore::new(100).percent(), 100);
+        assert_eq!(PasswordScore::new(101).percent(), 100);
+        assert_eq!(PasswordScore::new(i64::MAX).percent(), 100);
+    }
+
+    #[test]
+    fn test_evaluation_fraction_in_range() {
+        for (score, expected) in [(-20, 0.0), (0, 0.0), (42, 0.42), (100, 1.0), (250, 1.0)] {
+            let evaluation = PasswordEvaluation {
+                score: Some(PasswordScore::new(score)),
+                reasons: Vec::new(),
+            };
+            let fraction = evaluation.fraction();
+            assert!((0.0..=1.0).contains(&fraction));
+            assert!(
+                (fraction - expected).abs() < 1e-9,
+                "{} -> {}",
+                score,
+                fraction
+            );
+        }
+        let unscored = PasswordEvaluation {
+            score: None,
+            reasons: Vec::new(),
+        };
+        assert_eq!(unscored.fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_strength_fraction_matches_tier() {
+        for score in [0, 49, 50, 69, 70, 84, 85, 95, 96, 100] {
+            let evaluation = PasswordEvaluation {
+                score: Some(PasswordScore::new(score)),
+                reasons: Vec::new(),
+            };
+            let fraction = evaluation.strength().fraction();
+            assert!((0.0..=1.0).contains(&fraction));
+            // The midpoint falls in the same tier as the score
+            let midpoint = PasswordEvaluation {
+                score: Some(PasswordScore::new((fraction * 100.0).round() as i64)),
+                reasons: Vec::new(),
+            };
+            assert_eq!(midpoint.strength(), evaluation.strength());
+        }
+    }
+
+    #[test]
+    fn test_meets_threshold() {
+        assert!(PasswordScore::new(70).meets(70));
+        assert!(!PasswordScore::new(69).meets(70));
+    }
+}
@@ -1,26 +1,156 @@
 //! Blacklist section - checks if password is in common password list.
 
-use crate::blacklist::is_blacklisted;
+use super::homoglyph::{is_mixed_script, skeleton};
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::blacklist::{BlacklistSource, GlobalBlacklist};
+use crate::config::BlacklistMissingBehavior;
+use crate::reason::{Reason, ReasonCode};
 use secrecy::{ExposeSecret, SecretString};
-use super::SectionResult;
+use zeroize::Zeroizing;
+
+/// Reason message for a blacklisted password.
+///
+/// Does not quote a list size: the loaded list may be a short custom one
+/// or a large breach corpus.
+pub(crate) const BLACKLISTED_MESSAGE: &str = "Password is a known common password";
 
 /// Checks if the password is in the blacklist of common passwords.
 ///
+/// Lookups go to `EvaluatorConfig::blacklist_source`, or to the global
+/// blacklist if none is set.
+///
+/// Look-alike characters (e.g. Cyrillic "а" for Latin "a") are mapped to
+/// their ASCII skeleton before a second lookup, and passwords mixing Latin,
+/// Greek or Cyrillic letters are flagged as possible homoglyph evasion.
+/// A blacklisted password followed by up to two digits and one symbol
+/// (`"password1"`, `"qwerty!"`) is always flagged. With
+/// `EvaluatorConfig::blacklist_ignored_separators`, the password is also
+/// checked with those separators removed (`"pass-word"`). With `EvaluatorConfig::blacklist_strip_affixes`, the password is also
+/// checked with leading and trailing digits and symbols removed, and with
+/// `EvaluatorConfig::blacklist_substring_min_length` set, passwords
+/// containing a blacklisted entry are flagged as well.
+///
+/// Without a source or a loaded blacklist the section follows
+/// `EvaluatorConfig::blacklist_missing`.
+///
 /// # Returns
-/// - `Ok(Some(reason))` if password is blacklisted
+/// - `Ok(Some(reason))` if password is blacklisted or uses mixed scripts,
+///   or an advisory reason if no blacklist is loaded
 /// - `Ok(None)` if password is not in blacklist
-pub fn blacklist_section(password: &SecretString) -> SectionResult {
-    if is_blacklisted(password.expose_secret()) {
-        return Ok(Some(
-            "Password is in the top 10,000 most common".to_string(),
-        ));
+/// - `Err(())` if no blacklist is loaded and the behavior is `Error`
+pub fn blacklist_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    Ok(blacklist_section_code(password, ctx)?.map(|code| Reason::new(code, message(code))))
+}
+
+/// Like `blacklist_section`, returning only the code.
+pub(crate) fn blacklist_section_code(
+    password: &SecretString,
+    ctx: &SectionContext,
+) -> SectionCodeResult {
+    let pwd = password.expose_secret();
+    let unavailable = ctx.config.blacklist_unavailable();
+    if unavailable && ctx.config.blacklist_missing == BlacklistMissingBehavior::Error {
+        return Err(());
+    }
+    let source: &dyn BlacklistSource = ctx
+        .config
+        .blacklist_source
+        .as_deref()
+        .unwrap_or(&GlobalBlacklist);
+    if source.contains(pwd) {
+        return Ok(Some(ReasonCode::Blacklisted));
+    }
+    if skeleton(pwd).is_some_and(|s| source.contains(&s)) {
+        return Ok(Some(ReasonCode::LookAlike));
+    }
+    if has_trivial_suffix(pwd, source) {
+        return Ok(Some(ReasonCode::TrivialSuffix));
+    }
+    if let Some(separators) = &ctx.config.blacklist_ignored_separators
+        && is_blacklisted_without_separators(pwd, separators, source)
+    {
+        return Ok(Some(ReasonCode::BlacklistedSeparated));
+    }
+    if ctx.config.blacklist_strip_affixes && is_blacklisted_core(pwd, source) {
+        return Ok(Some(ReasonCode::BlacklistedCore));
+    }
+    if let Some(min_length) = ctx.config.blacklist_substring_min_length
+        && source.contains_substring(pwd, min_length)
+    {
+        return Ok(Some(ReasonCode::BlacklistedSubstring));
+    }
+    if is_mixed_script(pwd) {
+        return Ok(Some(ReasonCode::MixedScript));
+    }
+    if unavailable && ctx.config.blacklist_missing == BlacklistMissingBehavior::Warn {
+        return Ok(Some(ReasonCode::BlacklistNotLoaded));
     }
     Ok(None)
 }
 
+/// Message of a reason reported by `blacklist_section`.
+fn message(code: ReasonCode) -> &'static str {
+    match code {
+        ReasonCode::LookAlike => "Password imitates a common password with look-alike characters",
+        ReasonCode::TrivialSuffix => "Password is a common password with trivial modification",
+        ReasonCode::BlacklistedSeparated => {
+            "Password is a common password with separators inserted"
+        }
+        ReasonCode::BlacklistedCore => "Password is a common password with added digits or symbols",
+        ReasonCode::BlacklistedSubstring => "Password contains a common password",
+        ReasonCode::MixedScript => "Password mixes characters from multiple scripts",
+        ReasonCode::BlacklistNotLoaded => "Blacklist not loaded; common passwords were not checked",
+        _ => BLACKLISTED_MESSAGE,
+    }
+}
+
+/// Checks the password with a trailing run of up to two digits and one
+/// symbol stripped, e.g. `"password1!"` -> `"password"`.
+fn has_trivial_suffix(password: &str, source: &dyn BlacklistSource) -> bool {
+    let (mut digits, mut symbols) = (0, 0);
+    let mut end = password.len();
+    for (i, c) in password.char_indices().rev() {
+        if c.is_ascii_digit() && digits < 2 {
+            digits += 1;
+        } else if !c.is_alphanumeric() && symbols < 1 {
+            symbols += 1;
+        } else {
+            break;
+        }
+        end = i;
+    }
+    let core = &password[..end];
+    !core.is_empty() && end < password.len() && source.contains(core)
+}
+
+/// Checks the password with every character of `separators` removed,
+/// e.g. `"p-a-s-s-w-o-r-d"` -> `"password"`.
+fn is_blacklisted_without_separators(
+    password: &str,
+    separators: &str,
+    source: &dyn BlacklistSource,
+) -> bool {
+    let joined: Zeroizing<String> = Zeroizing::new(
+        password
+            .chars()
+            .filter(|&c| !separators.contains(c))
+            .collect(),
+    );
+    !joined.is_empty() && joined.len() < password.len() && source.contains(&joined)
+}
+
+/// Checks the password with leading and trailing non-alphabetic characters
+/// stripped, e.g. `"admin123"` -> `"admin"`.
+fn is_blacklisted_core(password: &str, source: &dyn BlacklistSource) -> bool {
+    let core = password.trim_matches(|c: char| !c.is_alphabetic());
+    !core.is_empty() && core.len() < password.len() && source.contains(core)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EvaluatorConfig;
+    use crate::sections::run_default;
     use serial_test::serial;
     use std::io::Write;
     use tempfile::NamedTempFile;
@@ -55,12 +185,28 @@ mod tests {
         let _ = crate::blacklist::init_blacklist();
 
         let pwd = SecretString::new("password".to_string().into());
-        let result = blacklist_section(&pwd);
+        let result = run_default(blacklist_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
 
         remove_env("PWD_BLACKLIST_PATH");
     }
 
+    #[test]
+    #[serial]
+    fn test_blacklist_section_message_with_small_list() {
+        crate::blacklist::reset_blacklist_for_testing();
+        crate::blacklist::init_blacklist_from_iter(["hunter2", "letmein"]).unwrap();
+
+        let pwd = SecretString::new("letmein".to_string().into());
+        let result = run_default(blacklist_section, &pwd);
+        assert!(matches!(
+            result,
+            Ok(Some(ref r)) if r.message == "Password is a known common password"
+        ));
+
+        crate::blacklist::reset_blacklist_for_testing();
+    }
+
     #[test]
     #[serial]
     fn test_blacklist_section_strong_password() {
@@ -73,9 +219,292 @@ mod tests {
         let _ = crate::blacklist::init_blacklist();
 
         let pwd = SecretString::new("CorrectHorseBatteryStaple!123".to_string().into());
-        let result = blacklist_section(&pwd);
+        let result = run_default(blacklist_section, &pwd);
         assert_eq!(result, Ok(None));
 
         remove_env("PWD_BLACKLIST_PATH");
     }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_homoglyph_password() {
+        crate::blacklist::reset_blacklist_for_testing();
+
+        let temp_file = setup_with_tempfile(&["password", "123456", "qwerty"]);
+        let path = temp_file.path().to_str().unwrap();
+        set_env("PWD_BLACKLIST_PATH", path);
+
+        let _ = crate::blacklist::init_blacklist();
+
+        // Cyrillic "а" (U+0430) in place of Latin "a"
+        let pwd = SecretString::new("p\u{0430}ssword".to_string().into());
+        let result = run_default(blacklist_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::LookAlike));
+
+        remove_env("PWD_BLACKLIST_PATH");
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_mixed_script_password() {
+        crate::blacklist::reset_blacklist_for_testing();
+
+        let temp_file = setup_with_tempfile(&["password", "123456", "qwerty"]);
+        let path = temp_file.path().to_str().unwrap();
+        set_env("PWD_BLACKLIST_PATH", path);
+
+        let _ = crate::blacklist::init_blacklist();
+
+        // Cyrillic "ж" has no Latin skeleton but still mixes scripts
+        let pwd = SecretString::new("Sunny\u{0436}Day42!".to_string().into());
+        let result = run_default(blacklist_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::MixedScript));
+
+        remove_env("PWD_BLACKLIST_PATH");
+    }
+
+    fn run_with_substring_mode(password: &str) -> SectionResult {
+        let config = EvaluatorConfig {
+            blacklist_substring_min_length: Some(4),
+            ..Default::default()
+        };
+        let pwd = SecretString::new(password.to_string().into());
+        blacklist_section(&pwd, &SectionContext::new(&config))
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_substring_mode_flags_contained_entry() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "123456", "qwerty", "a"]);
+
+        let result = run_with_substring_mode("mypassword!");
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::BlacklistedSubstring));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_substring_mode_passes_clean_password() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "123456", "qwerty", "a"]);
+
+        // Contains "a", which is below the minimum entry length
+        let result = run_with_substring_mode("Kx7#mQ2!vRa9z");
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_substring_mode_off_by_default() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "123456", "qwerty"]);
+
+        let pwd = SecretString::new("mypassword!".to_string().into());
+        assert_eq!(run_default(blacklist_section, &pwd), Ok(None));
+    }
+
+    fn run_with_affix_stripping(password: &str) -> SectionResult {
+        let config = EvaluatorConfig {
+            blacklist_strip_affixes: true,
+            ..Default::default()
+        };
+        let pwd = SecretString::new(password.to_string().into());
+        blacklist_section(&pwd, &SectionContext::new(&config))
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_strip_affixes_flags_suffix() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        let result = run_with_affix_stripping("admin123");
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::BlacklistedCore));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_strip_affixes_flags_prefix() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        let result = run_with_affix_stripping("!qwerty");
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::BlacklistedCore));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_strip_affixes_passes_legit_password() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        // Strips to "Kx7#mQ2!vRz", which is not listed
+        assert_eq!(run_with_affix_stripping("9Kx7#mQ2!vRz42"), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_ignored_separators() {
+        use crate::config::DEFAULT_BLACKLIST_SEPARATORS;
+
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+        let config = EvaluatorConfig {
+            blacklist_ignored_separators: Some(DEFAULT_BLACKLIST_SEPARATORS.to_string()),
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        for password in ["p-a-s-s-w-o-r-d", "pass.word", "q w e r t y", "ad_min"] {
+            let pwd = SecretString::new(password.to_string().into());
+            let result = blacklist_section(&pwd, &ctx);
+            assert!(
+                matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::BlacklistedSeparated),
+                "{password}: {result:?}"
+            );
+        }
+        let pwd = SecretString::new("Kx7-mQ2.vRz".to_string().into());
+        assert_eq!(blacklist_section(&pwd, &ctx), Ok(None));
+
+        // Opt-in: exact lookups by default
+        let pwd = SecretString::new("p-a-s-s-w-o-r-d".to_string().into());
+        assert_eq!(run_default(blacklist_section, &pwd), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_flags_trivial_suffix() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        for password in ["password1", "qwerty!", "admin12", "password1!"] {
+            let pwd = SecretString::new(password.to_string().into());
+            let result = run_default(blacklist_section, &pwd);
+            assert!(
+                matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::TrivialSuffix),
+                "{password}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_ignores_capitalization() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password"]);
+
+        for password in ["Password", "PASSWORD", "PassWord", "pAsSwOrD", "passworD"] {
+            let pwd = SecretString::new(password.to_string().into());
+            let result = run_default(blacklist_section, &pwd);
+            assert!(
+                matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::Blacklisted),
+                "{password}: {result:?}"
+            );
+        }
+        // The trivial-suffix check sees the same case-folded core
+        let pwd = SecretString::new("PASSWORD1!".to_string().into());
+        assert!(matches!(
+            run_default(blacklist_section, &pwd),
+            Ok(Some(ref r)) if r.code == ReasonCode::TrivialSuffix
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_trivial_suffix_limits() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        // Longer runs, prefixes and extra letters are left to the opt-in checks
+        for password in ["admin123", "qwerty!!", "1admin", "admins1"] {
+            let pwd = SecretString::new(password.to_string().into());
+            assert_eq!(run_default(blacklist_section, &pwd), Ok(None), "{password}");
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_strip_affixes_off_by_default() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password", "admin", "qwerty"]);
+
+        let pwd = SecretString::new("admin123".to_string().into());
+        assert_eq!(run_default(blacklist_section, &pwd), Ok(None));
+    }
+
+    #[derive(Debug)]
+    struct MockSource(Vec<&'static str>);
+
+    impl BlacklistSource for MockSource {
+        fn contains(&self, password: &str) -> bool {
+            self.0.iter().any(|p| p.eq_ignore_ascii_case(password))
+        }
+
+        fn contains_substring(&self, password: &str, min_entry_length: usize) -> bool {
+            let password = password.to_lowercase();
+            self.0
+                .iter()
+                .any(|p| p.len() >= min_entry_length && password.contains(p))
+        }
+    }
+
+    /// A source without substring support.
+    #[derive(Debug)]
+    struct ExactSource(&'static str);
+
+    impl BlacklistSource for ExactSource {
+        fn contains(&self, password: &str) -> bool {
+            self.0.eq_ignore_ascii_case(password)
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_custom_source() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password"]);
+        let config = EvaluatorConfig {
+            blacklist_source: Some(std::sync::Arc::new(MockSource(vec!["hunter2"]))),
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        let listed = SecretString::new("Hunter2".to_string().into());
+        let result = blacklist_section(&listed, &ctx);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::Blacklisted));
+
+        // The global list is not consulted when a source is set
+        let global = SecretString::new("password".to_string().into());
+        assert_eq!(blacklist_section(&global, &ctx), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_blacklist_section_custom_source_substring() {
+        crate::blacklist::reset_blacklist_for_testing();
+        let _ = crate::blacklist::init_blacklist_from_iter(["password"]);
+        let config = EvaluatorConfig {
+            blacklist_source: Some(std::sync::Arc::new(MockSource(vec!["hunter2"]))),
+            blacklist_substring_min_length: Some(4),
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        let listed = SecretString::new("MyHunter2!".to_string().into());
+        let result = blacklist_section(&listed, &ctx);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::BlacklistedSubstring));
+
+        // Substrings are looked up in the source, not the global list
+        let global = SecretString::new("mypassword!".to_string().into());
+        assert_eq!(blacklist_section(&global, &ctx), Ok(None));
+
+        // Sources without substring support skip substring matching
+        let config = EvaluatorConfig {
+            blacklist_source: Some(std::sync::Arc::new(ExactSource("hunter2"))),
+            ..config
+        };
+        let ctx = SectionContext::new(&config);
+        assert_eq!(blacklist_section(&listed, &ctx), Ok(None));
+        assert_eq!(blacklist_section(&global, &ctx), Ok(None));
+    }
 }
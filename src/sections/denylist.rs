@@ -0,0 +1,59 @@
+//! Denylist section - checks for organization-specific forbidden terms.
+
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::denylist::contains_denied_term;
+use crate::reason::{Reason, ReasonCode};
+use secrecy::{ExposeSecret, SecretString};
+
+/// Checks if the password contains a term from the denylist.
+///
+/// Matching is case-insensitive and anywhere in the password, so
+/// `"AcmeCorp2024!"` is caught when `"acmecorp"` is listed. Passes if no
+/// denylist has been loaded with `init_denylist_from_iter`.
+///
+/// # Returns
+/// - `Ok(Some(reason))` if the password contains a denylisted term
+/// - `Ok(None)` otherwise
+pub fn denylist_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    Ok(denylist_section_code(password, ctx)?
+        .map(|code| Reason::new(code, "Password contains a forbidden term")))
+}
+
+/// Like `denylist_section`, returning only the code.
+pub(crate) fn denylist_section_code(
+    password: &SecretString,
+    _ctx: &SectionContext,
+) -> SectionCodeResult {
+    Ok(contains_denied_term(password.expose_secret()).then_some(ReasonCode::DeniedTerm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::run_default;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn test_denylist_section_flags_term() {
+        crate::denylist::reset_denylist_for_testing();
+        crate::denylist::init_denylist_from_iter(["acmecorp"]).unwrap();
+
+        let pwd = SecretString::new("acmecorp123".to_string().into());
+        let result = run_default(denylist_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::DeniedTerm));
+
+        let pwd = SecretString::new("Kx7#mQ2!vR9$".to_string().into());
+        assert_eq!(run_default(denylist_section, &pwd), Ok(None));
+
+        crate::denylist::reset_denylist_for_testing();
+    }
+
+    #[test]
+    #[serial]
+    fn test_denylist_section_passes_without_denylist() {
+        crate::denylist::reset_denylist_for_testing();
+        let pwd = SecretString::new("acmecorp123".to_string().into());
+        assert_eq!(run_default(denylist_section, &pwd), Ok(None));
+    }
+}
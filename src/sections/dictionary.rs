@@ -0,0 +1,141 @@
+//! Dictionary section - checks if password is a single dictionary word.
+
+use super::leet::unleet;
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::dictionary::is_dictionary_word_for;
+use crate::locale::Locale;
+use crate::reason::{Reason, ReasonCode};
+use secrecy::{ExposeSecret, SecretString};
+
+/// Minimum length, in characters, of a word the section looks up.
+const MIN_WORD_LENGTH: usize = 4;
+
+/// Checks if the alphabetic core of the password is a dictionary word.
+///
+/// Leading and trailing non-alphabetic characters are stripped first, so
+/// `"Elephant9"` and `"!elephant"` are both caught. The password and its
+/// core are then looked up with leet substitutions undone (`"0"` -> `"o"`,
+/// `"$"` -> `"s"`), so `"P@$$w0rd"` and `"l3tm31n"` are caught as well.
+/// Words are looked up in the global dictionary and in the word list of
+/// `EvaluatorConfig::locale`, if one is loaded. Passes if no dictionary
+/// has been loaded with `init_dictionary_from_path` or
+/// `init_locale_dictionary_from_path`.
+///
+/// # Returns
+/// - `Ok(Some(reason))` if the password is a single dictionary word,
+///   possibly obfuscated
+/// - `Ok(None)` otherwise
+pub fn dictionary_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    Ok(dictionary_section_code(password, ctx)?.map(|code| {
+        let message = match code {
+            ReasonCode::ObfuscatedDictionaryWord => {
+                "A dictionary word with substituted characters is weak"
+            }
+            _ => "A single dictionary word is weak",
+        };
+        Reason::new(code, message)
+    }))
+}
+
+/// Like `dictionary_section`, returning only the code.
+pub(crate) fn dictionary_section_code(
+    password: &SecretString,
+    ctx: &SectionContext,
+) -> SectionCodeResult {
+    let pwd = password.expose_secret();
+    let locale = ctx.config.locale;
+    let core = alphabetic_core(pwd);
+    if is_word(core, locale) {
+        return Ok(Some(ReasonCode::DictionaryWord));
+    }
+    if [pwd, core]
+        .into_iter()
+        .any(|s| unleet(s).is_some_and(|plain| is_word(alphabetic_core(&plain), locale)))
+    {
+        return Ok(Some(ReasonCode::ObfuscatedDictionaryWord));
+    }
+    Ok(None)
+}
+
+/// `s` without leading and trailing non-alphabetic characters.
+fn alphabetic_core(s: &str) -> &str {
+    s.trim_matches(|c: char| !c.is_alphabetic())
+}
+
+fn is_word(s: &str, locale: Locale) -> bool {
+    s.chars().count() >= MIN_WORD_LENGTH && is_dictionary_word_for(s, locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EvaluatorConfig;
+    use crate::sections::run_default;
+    use serial_test::serial;
+
+    fn setup_dictionary() {
+        crate::dictionary::reset_dictionary_for_testing();
+        let _ = crate::dictionary::init_dictionary_from_iter([
+            "elephant",
+            "sunshine",
+            "cat",
+            "password",
+            "letmein",
+            "microsoft",
+        ]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_dictionary_section_flags_word() {
+        setup_dictionary();
+        let pwd = SecretString::new("Elephant9".to_string().into());
+        let result = run_default(dictionary_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::DictionaryWord));
+    }
+
+    #[test]
+    #[serial]
+    fn test_dictionary_section_passes_non_word() {
+        setup_dictionary();
+        let pwd = SecretString::new("Xqlethno9".to_string().into());
+        assert_eq!(run_default(dictionary_section, &pwd), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_dictionary_section_flags_obfuscated_word() {
+        setup_dictionary();
+        for pwd in ["P@$$w0rd", "l3tm31n", "Micr0$oft", "$unshine", "P@$$w0rd1"] {
+            let secret = SecretString::new(pwd.to_string().into());
+            let result = run_default(dictionary_section, &secret);
+            assert!(
+                matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::ObfuscatedDictionaryWord),
+                "{pwd}: {result:?}"
+            );
+        }
+        let pwd = SecretString::new("Xq1eth0no9".to_string().into());
+        assert_eq!(run_default(dictionary_section, &pwd), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_dictionary_section_ignores_short_words() {
+        setup_dictionary();
+        let pwd = SecretString::new("cat12345".to_string().into());
+        assert_eq!(run_default(dictionary_section, &pwd), Ok(None));
+    }
+
+    #[test]
+    #[serial]
+    fn test_dictionary_section_uses_locale_word_list() {
+        setup_dictionary();
+        let _ = crate::dictionary::init_locale_dictionary_from_iter(Locale::French, ["soleil"]);
+        let pwd = SecretString::new("Soleil42".to_string().into());
+        assert_eq!(run_default(dictionary_section, &pwd), Ok(None));
+
+        let config = EvaluatorConfig::for_locale(Locale::French);
+        let result = dictionary_section(&pwd, &SectionContext::new(&config));
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::DictionaryWord));
+    }
+}
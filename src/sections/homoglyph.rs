@@ -0,0 +1,131 @@
+//! Homoglyph helpers - confusable skeletons and mixed-script detection.
+//!
+//! Used by the blacklist section to catch passwords such as "pаssword"
+//! (Cyrillic "а") that look identical to a blacklisted entry.
+
+use zeroize::Zeroizing;
+
+/// Look-alike characters mapped to their ASCII skeleton.
+///
+/// A small subset of the Unicode confusables table covering the Greek and
+/// Cyrillic letters that are visually indistinguishable from Latin ones.
+#[rustfmt::skip]
+const CONFUSABLES: &[(char, char)] = &[
+    // Cyrillic lowercase
+    ('а', 'a'), ('в', 'b'), ('е', 'e'), ('һ', 'h'), ('і', 'i'), ('ј', 'j'),
+    ('к', 'k'), ('м', 'm'), ('н', 'h'), ('о', 'o'), ('р', 'p'), ('с', 'c'),
+    ('ѕ', 's'), ('т', 't'), ('у', 'y'), ('х', 'x'), ('ԁ', 'd'), ('ԛ', 'q'),
+    ('ԝ', 'w'),
+    // Cyrillic uppercase
+    ('А', 'A'), ('В', 'B'), ('Е', 'E'), ('І', 'I'), ('Ј', 'J'), ('К', 'K'),
+    ('М', 'M'), ('Н', 'H'), ('О', 'O'), ('Р', 'P'), ('С', 'C'), ('Ѕ', 'S'),
+    ('Т', 'T'), ('Х', 'X'), ('Ү', 'Y'),
+    // Greek lowercase
+    ('α', 'a'), ('ι', 'i'), ('κ', 'k'), ('ν', 'v'), ('ο', 'o'), ('ρ', 'p'),
+    ('υ', 'u'),
+    // Greek uppercase
+    ('Α', 'A'), ('Β', 'B'), ('Ε', 'E'), ('Ζ', 'Z'), ('Η', 'H'), ('Ι', 'I'),
+    ('Κ', 'K'), ('Μ', 'M'), ('Ν', 'N'), ('Ο', 'O'), ('Ρ', 'P'), ('Τ', 'T'),
+    ('Υ', 'Y'), ('Χ', 'X'),
+];
+
+/// Scripts that are commonly confused with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+}
+
+fn script_of(c: char) -> Option<Script> {
+    match c as u32 {
+        0x41..=0x5A | 0x61..=0x7A => Some(Script::Latin),
+        0xC0..=0x24F | 0x1E00..=0x1EFF if c.is_alphabetic() => Some(Script::Latin),
+        0xFF21..=0xFF3A | 0xFF41..=0xFF5A => Some(Script::Latin),
+        0x370..=0x3FF | 0x1F00..=0x1FFF if c.is_alphabetic() => Some(Script::Greek),
+        0x400..=0x52F if c.is_alphabetic() => Some(Script::Cyrillic),
+        _ => None,
+    }
+}
+
+fn skeleton_char(c: char) -> char {
+    match c as u32 {
+        // Fullwidth Latin letters map onto ASCII by a fixed offset
+        0xFF21..=0xFF3A | 0xFF41..=0xFF5A => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => CONFUSABLES
+            .iter()
+            .find(|(from, _)| *from == c)
+            .map(|(_, to)| *to)
+            .unwrap_or(c),
+    }
+}
+
+/// Returns the ASCII skeleton of `s`, or `None` if it contains no confusables.
+///
+/// The skeleton is derived from the password, so it is zeroized on drop.
+pub fn skeleton(s: &str) -> Option<Zeroizing<String>> {
+    let skeleton: Zeroizing<String> = Zeroizing::new(s.chars().map(skeleton_char).collect());
+    if *skeleton == s { None } else { Some(skeleton) }
+}
+
+/// Returns `true` if `s` mixes letters from Latin, Greek or Cyrillic.
+///
+/// Letters from other scripts are ignored, so legitimate combinations such
+/// as Latin with CJK are not reported.
+pub fn is_mixed_script(s: &str) -> bool {
+    let mut first: Option<Script> = None;
+    for script in s.chars().filter_map(script_of) {
+        match first {
+            None => first = Some(script),
+            Some(f) if f != script => return true,
+            Some(_) => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skeleton_of(s: &str) -> Option<String> {
+        skeleton(s).map(|s| s.to_string())
+    }
+
+    #[test]
+    fn test_skeleton_maps_cyrillic_lookalikes() {
+        assert_eq!(skeleton_of("p\u{0430}ssword"), Some("password".to_string()));
+        assert_eq!(
+            skeleton_of("\u{0420}\u{0410}SSWORD"),
+            Some("PASSWORD".to_string())
+        );
+    }
+
+    #[test]
+    fn test_skeleton_maps_fullwidth_latin() {
+        assert_eq!(skeleton_of("\u{FF41}dmin"), Some("admin".to_string()));
+    }
+
+    #[test]
+    fn test_skeleton_is_zeroized_on_drop() {
+        // The skeleton is a plaintext copy of the password: it must be
+        // returned in a buffer that is cleared when dropped
+        let skeleton: Option<Zeroizing<String>> = skeleton("p\u{0430}ssword");
+        assert!(skeleton.is_some());
+    }
+
+    #[test]
+    fn test_skeleton_plain_ascii() {
+        assert_eq!(skeleton_of("password"), None);
+    }
+
+    #[test]
+    fn test_is_mixed_script() {
+        assert!(is_mixed_script("p\u{0430}ssword"));
+        assert!(!is_mixed_script("password123"));
+        assert!(!is_mixed_script(
+            "\u{043f}\u{0430}\u{0440}\u{043e}\u{043b}\u{044c}"
+        ));
+        assert!(!is_mixed_script("Tokyo\u{6771}\u{4eac}"));
+    }
+}
@@ -0,0 +1,51 @@
+//! Leet helpers - undoes common digit and symbol substitutions.
+//!
+//! Used by the dictionary section to catch passwords such as "Micr0$oft"
+//! that spell a dictionary word with look-alike digits or symbols.
+
+use zeroize::Zeroizing;
+
+/// Digits and symbols mapped to the letter they usually stand for.
+#[rustfmt::skip]
+const SUBSTITUTIONS: &[(char, char)] = &[
+    ('0', 'o'), ('1', 'i'), ('3', 'e'), ('4', 'a'), ('5', 's'), ('7', 't'),
+    ('8', 'b'), ('9', 'g'), ('@', 'a'), ('$', 's'), ('!', 'i'), ('+', 't'),
+    ('|', 'l'),
+];
+
+fn unleet_char(c: char) -> char {
+    SUBSTITUTIONS
+        .iter()
+        .find(|(from, _)| *from == c)
+        .map(|(_, to)| *to)
+        .unwrap_or(c)
+}
+
+/// Returns `s` with substitutions undone, or `None` if it contains none.
+///
+/// The result is derived from the password, so it is zeroized on drop.
+pub fn unleet(s: &str) -> Option<Zeroizing<String>> {
+    let plain: Zeroizing<String> = Zeroizing::new(s.chars().map(unleet_char).collect());
+    if *plain == s { None } else { Some(plain) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unleet_of(s: &str) -> Option<String> {
+        unleet(s).map(|s| s.to_string())
+    }
+
+    #[test]
+    fn test_unleet_maps_substitutions() {
+        assert_eq!(unleet_of("P@$$w0rd"), Some("Password".to_string()));
+        assert_eq!(unleet_of("l3tm31n"), Some("letmein".to_string()));
+        assert_eq!(unleet_of("Micr0$oft"), Some("Microsoft".to_string()));
+    }
+
+    #[test]
+    fn test_unleet_plain_letters() {
+        assert_eq!(unleet_of("password"), None);
+    }
+}
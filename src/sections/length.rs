@@ -1,50 +1,111 @@
 //! Length section - checks password minimum length.
 
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::core::{check_length, length_code};
 use secrecy::{ExposeSecret, SecretString};
-use super::SectionResult;
 
-const MIN_LENGTH: usize = 8;
-
-/// Checks if the password meets minimum length requirements.
+/// Checks if the password is at least `EvaluatorConfig::min_length` bytes long.
+///
+/// With `EvaluatorConfig::length_advisory_band` set, passwords within the
+/// band above the minimum get an advisory `ReasonCode::MarginalLength`.
+/// See `core::check_length`.
 ///
 /// # Returns
-/// - `Ok(Some(reason))` if password is too short
+/// - `Ok(Some(reason))` if password is too short or only marginally long enough
 /// - `Ok(None)` if password has sufficient length
-pub fn length_section(password: &SecretString) -> SectionResult {
-    if password.expose_secret().len() < MIN_LENGTH {
-        return Ok(Some(format!(
-            "Password must be at least {} characters",
-            MIN_LENGTH
-        )));
-    }
-    Ok(None)
+pub fn length_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    Ok(check_length(
+        password.expose_secret(),
+        ctx.config.min_length,
+        ctx.config.length_advisory_band,
+    ))
+}
+
+/// Like `length_section`, returning only the code.
+pub(crate) fn length_section_code(
+    password: &SecretString,
+    ctx: &SectionContext,
+) -> SectionCodeResult {
+    Ok(length_code(
+        password.expose_secret(),
+        ctx.config.min_length,
+        ctx.config.length_advisory_band,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EvaluatorConfig;
+    use crate::reason::{Reason, ReasonCode};
+    use crate::sections::run_default;
 
     #[test]
     fn test_length_section_too_short() {
         let pwd = SecretString::new("Short1!".to_string().into());
-        let result = length_section(&pwd);
+        let result = run_default(length_section, &pwd);
         assert_eq!(
             result,
-            Ok(Some("Password must be at least 8 characters".to_string()))
+            Ok(Some(Reason::new(
+                ReasonCode::TooShort,
+                "Password must be at least 8 characters"
+            )))
         );
     }
 
     #[test]
     fn test_length_section_exactly_minimum() {
         let pwd = SecretString::new("12345678".to_string().into());
-        let result = length_section(&pwd);
+        let result = run_default(length_section, &pwd);
         assert_eq!(result, Ok(None));
     }
 
     #[test]
     fn test_length_section_valid() {
         let pwd = SecretString::new("LongEnough123!".to_string().into());
-        let result = length_section(&pwd);
+        let result = run_default(length_section, &pwd);
         assert_eq!(result, Ok(None));
     }
+
+    #[test]
+    fn test_length_section_advisory_band() {
+        let config = EvaluatorConfig {
+            length_advisory_band: 4,
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        let marginal = SecretString::new("Abcd12!x".to_string().into());
+        assert_eq!(
+            length_section(&marginal, &ctx),
+            Ok(Some(Reason::new(
+                ReasonCode::MarginalLength,
+                "Password length is minimal; consider 12+"
+            )))
+        );
+
+        let long = SecretString::new("Abcd12!xAbcd12!x".to_string().into());
+        assert_eq!(length_section(&long, &ctx), Ok(None));
+    }
+
+    #[test]
+    fn test_length_section_custom_min_length() {
+        let config = EvaluatorConfig {
+            min_length: 12,
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        let pwd = SecretString::new("Abcd12!xyz".to_string().into());
+        assert_eq!(
+            length_section(&pwd, &ctx),
+            Ok(Some(Reason::new(
+                ReasonCode::TooShort,
+                "Password must be at least 12 characters"
+            )))
+        );
+
+        let pwd = SecretString::new("Abcd12!xyzAB".to_string().into());
+        assert_eq!(length_section(&pwd, &ctx), Ok(None));
+    }
 }
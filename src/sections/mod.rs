@@ -3,17 +3,350 @@
 //! Each section analyzes a specific aspect of password strength.
 
 mod blacklist;
+mod denylist;
+mod dictionary;
+mod homoglyph;
+mod leet;
 mod length;
 mod pattern;
 mod variety;
 
+#[cfg(feature = "async")]
+pub(crate) use blacklist::BLACKLISTED_MESSAGE;
 pub use blacklist::blacklist_section;
+pub use denylist::denylist_section;
+pub use dictionary::dictionary_section;
 pub use length::length_section;
 pub use pattern::pattern_analysis_section;
 pub use variety::character_variety_section;
 
+use secrecy::SecretString;
+
+#[cfg(feature = "async")]
+use tokio_util::sync::CancellationToken;
+
+use crate::config::EvaluatorConfig;
+use crate::reason::{Reason, ReasonCode, Severity};
+
 /// Result type for section evaluation functions.
 /// - `Ok(Some(reason))` - Section failed with reason
 /// - `Ok(None)` - Section passed
 /// - `Err(())` - Fatal error during evaluation
-pub type SectionResult = Result<Option<String>, ()>;
+pub type SectionResult = Result<Option<Reason>, ()>;
+
+/// Signature shared by all section functions.
+pub type SectionFn = fn(&SecretString, &SectionContext) -> SectionResult;
+
+/// Like `SectionResult`, with only the reason code, so no message is built.
+pub(crate) type SectionCodeResult = Result<Option<ReasonCode>, ()>;
+
+/// Signature shared by the code-only section functions.
+pub(crate) type SectionCodeFn = fn(&SecretString, &SectionContext) -> SectionCodeResult;
+
+/// A built-in section, used to order and toggle sections in
+/// `EvaluatorConfig::sections`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Section {
+    /// Common passwords, look-alikes and mixed scripts.
+    Blacklist,
+    /// Organization-specific forbidden terms (needs `init_denylist_from_iter`).
+    Denylist,
+    /// Minimum length.
+    Length,
+    /// Character types.
+    Variety,
+    /// Repeats, sequences, keyboard walks and palindromes.
+    Pattern,
+    /// Single dictionary words (needs `init_dictionary_from_path`).
+    Dictionary,
+}
+
+impl Section {
+    /// Sections run by the default configuration, in order.
+    pub const DEFAULT: [Section; 5] = [
+        Section::Blacklist,
+        Section::Denylist,
+        Section::Length,
+        Section::Variety,
+        Section::Pattern,
+    ];
+
+    /// Name used in tracing spans and stream events.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Section::Blacklist => "blacklist",
+            Section::Denylist => "denylist",
+            Section::Length => "length",
+            Section::Variety => "variety",
+            Section::Pattern => "pattern",
+            Section::Dictionary => "dictionary",
+        }
+    }
+
+    /// Returns what the section checks, for documenting a policy.
+    pub const fn description(self) -> SectionDescription {
+        let (summary, codes): (&'static str, &'static [ReasonCode]) = match self {
+            Section::Blacklist => (
+                "Rejects common passwords, including look-alike spellings, trivial suffixes and mixed scripts",
+                &[
+                    ReasonCode::Blacklisted,
+                    ReasonCode::LookAlike,
+                    ReasonCode::TrivialSuffix,
+                    ReasonCode::BlacklistedSeparated,
+                    ReasonCode::BlacklistedCore,
+                    ReasonCode::BlacklistedSubstring,
+                    ReasonCode::MixedScript,
+                    ReasonCode::BlacklistNotLoaded,
+                ],
+            ),
+            Section::Denylist => (
+                "Rejects passwords containing an organization-specific forbidden term",
+                &[ReasonCode::DeniedTerm],
+            ),
+            Section::Length => (
+                "Requires a minimum length and advises on lengths just above it",
+                &[ReasonCode::TooShort, ReasonCode::MarginalLength],
+            ),
+            Section::Variety => (
+                "Requires a character of each required class",
+                &[ReasonCode::MissingCharacterTypes],
+            ),
+            Section::Pattern => (
+                "Rejects repeats, sequences, keyboard walks, palindromes, phone numbers and dates",
+                &[
+                    ReasonCode::Repetitive,
+                    ReasonCode::Sequential,
+                    ReasonCode::KeyboardWalk,
+                    ReasonCode::Palindrome,
+                    ReasonCode::PhoneNumber,
+                    ReasonCode::Date,
+                ],
+            ),
+            Section::Dictionary => (
+                "Rejects single dictionary words, also with substituted characters",
+                &[
+                    ReasonCode::DictionaryWord,
+                    ReasonCode::ObfuscatedDictionaryWord,
+                ],
+            ),
+        };
+        SectionDescription {
+            section: self,
+            name: self.name(),
+            summary,
+            codes,
+        }
+    }
+
+    /// Function implementing the section.
+    pub(crate) const fn function(self) -> SectionFn {
+        match self {
+            Section::Blacklist => blacklist_section,
+            Section::Denylist => denylist_section,
+            Section::Length => length_section,
+            Section::Variety => character_variety_section,
+            Section::Pattern => pattern_analysis_section,
+            Section::Dictionary => dictionary_section,
+        }
+    }
+
+    /// Like `function`, returning only the reason code; used by
+    /// `strength_only`.
+    pub(crate) const fn code_function(self) -> SectionCodeFn {
+        match self {
+            Section::Blacklist => blacklist::blacklist_section_code,
+            Section::Denylist => denylist::denylist_section_code,
+            Section::Length => length::length_section_code,
+            Section::Variety => variety::variety_section_code,
+            Section::Pattern => pattern::pattern_section_code,
+            Section::Dictionary => dictionary::dictionary_section_code,
+        }
+    }
+
+    /// Returns `true` if the section reads global state (a loaded list).
+    pub(crate) const fn uses_global_state(self) -> bool {
+        matches!(
+            self,
+            Section::Blacklist | Section::Denylist | Section::Dictionary
+        )
+    }
+}
+
+/// Machine-readable description of a section; see `describe_sections`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionDescription {
+    /// The section.
+    pub section: Section,
+    /// Same as `Section::name`.
+    pub name: &'static str,
+    /// One-sentence summary of what the section checks.
+    pub summary: &'static str,
+    /// Reason codes the section can report.
+    pub codes: &'static [ReasonCode],
+}
+
+impl SectionDescription {
+    /// Returns `true` if every reason the section can report is advisory
+    /// (`Severity::Info`), so it never costs points or fails a requirement.
+    pub fn is_advisory(&self) -> bool {
+        self.codes
+            .iter()
+            .all(|code| code.severity() == Severity::Info)
+    }
+}
+
+/// Describes the sections `config` runs, in order, for tooling that
+/// documents the effective password policy.
+///
+/// # Example
+///
+/// ```rust
+/// use pwd_strength::{describe_sections, EvaluatorConfig};
+///
+/// for section in describe_sections(&EvaluatorConfig::default()) {
+///     println!("{}: {}", section.name, section.summary);
+/// }
+/// ```
+pub fn describe_sections(config: &EvaluatorConfig) -> Vec<SectionDescription> {
+    config
+        .sections
+        .iter()
+        .map(|section| section.description())
+        .collect()
+}
+
+/// State shared with every section during an evaluation.
+pub struct SectionContext<'a> {
+    /// Configuration of the running evaluation.
+    pub config: &'a EvaluatorConfig,
+    #[cfg(feature = "async")]
+    token: Option<&'a CancellationToken>,
+}
+
+impl<'a> SectionContext<'a> {
+    /// Creates a context without cancellation.
+    pub fn new(config: &'a EvaluatorConfig) -> Self {
+        Self {
+            config,
+            #[cfg(feature = "async")]
+            token: None,
+        }
+    }
+
+    /// Attaches a cancellation token.
+    #[cfg(feature = "async")]
+    pub fn with_token(mut self, token: Option<&'a CancellationToken>) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Returns `true` if the evaluation has been cancelled.
+    ///
+    /// Long-running sections should poll this periodically and return
+    /// `Ok(None)` early; the evaluator discards the result of a cancelled
+    /// run. Always `false` without the `async` feature.
+    pub fn is_cancelled(&self) -> bool {
+        #[cfg(feature = "async")]
+        {
+            self.token.is_some_and(|t| t.is_cancelled())
+        }
+        #[cfg(not(feature = "async"))]
+        {
+            false
+        }
+    }
+}
+
+/// Runs `section` with the default configuration and no cancellation.
+#[cfg(test)]
+pub(crate) fn run_default(section: SectionFn, password: &SecretString) -> SectionResult {
+    section(password, &SectionContext::new(&EvaluatorConfig::default()))
+}
+
+/// Loads the small blacklist shared by module tests.
+#[cfg(test)]
+pub(crate) fn setup_blacklist() {
+    crate::blacklist::reset_blacklist_for_testing();
+    let _ = crate::blacklist::init_blacklist_from_iter(["password", "123456", "qwerty"]);
+}
+
+#[cfg(test)]
+pub(crate) fn secret(password: &str) -> SecretString {
+    SecretString::new(password.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_default_sections() {
+        let descriptions = describe_sections(&EvaluatorConfig::default());
+        let names: Vec<_> = descriptions.iter().map(|d| d.name).collect();
+        assert_eq!(
+            names,
+            ["blacklist", "denylist", "length", "variety", "pattern"]
+        );
+        for (description, section) in descriptions.iter().zip(Section::DEFAULT) {
+            assert_eq!(description.section, section);
+            assert!(!description.summary.is_empty() && !description.codes.is_empty());
+            assert!(!description.is_advisory());
+        }
+
+        let config = EvaluatorConfig {
+            sections: vec![Section::Dictionary],
+            ..Default::default()
+        };
+        assert_eq!(
+            describe_sections(&config),
+            [Section::Dictionary.description()]
+        );
+        assert!(
+            Section::Variety
+                .description()
+                .codes
+                .contains(&ReasonCode::MissingCharacterTypes)
+        );
+    }
+
+    #[test]
+    fn test_code_functions_match_sections() {
+        let config = EvaluatorConfig {
+            length_advisory_band: 4,
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+        let sections = [
+            Section::Denylist,
+            Section::Length,
+            Section::Variety,
+            Section::Pattern,
+        ];
+        let passwords = [
+            "short",
+            "Abcd12!xy",
+            "lowercase123!",
+            "aaaaBBBB1111",
+            "racecar",
+            "Kx7#mQ2!vR9$wL4@",
+        ];
+        for pwd in passwords {
+            let secret = SecretString::new(pwd.to_string().into());
+            for section in sections {
+                let codes = section.code_function()(&secret, &ctx);
+                let reasons = section.function()(&secret, &ctx);
+                assert_eq!(
+                    codes,
+                    reasons.map(|r| r.map(|r| r.code)),
+                    "{pwd} {}",
+                    section.name()
+                );
+            }
+        }
+    }
+}
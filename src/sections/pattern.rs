@@ -1,102 +1,219 @@
 //! Pattern analysis section - detects repetitive and sequential patterns.
 
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::core::{PatternRules, pattern_code_cancellable, pattern_reason};
 use secrecy::{ExposeSecret, SecretString};
-use super::SectionResult;
 
 /// Analyzes password for repetitive and sequential patterns.
 ///
+/// A run of `EvaluatorConfig::repeat_threshold` identical characters is
+/// flagged as repetitive, and a run of `EvaluatorConfig::sequential_threshold`
+/// letters (ignoring case) or digits stepping by one is flagged as
+/// sequential; punctuation never is.
+/// Keyboard walks on `EvaluatorConfig::keyboard_layout`, palindromes, and
+/// phone numbers or dates making up most of the password are flagged as
+/// well; see `core::check_patterns`.
+///
+/// Polls for cancellation periodically so long inputs can be interrupted
+/// partway. The characters are copied into a buffer that is zeroized when
+/// the section returns.
+///
 /// # Returns
 /// - `Ok(Some(reason))` if problematic patterns found
 /// - `Ok(None)` if no problematic patterns
-pub fn pattern_analysis_section(password: &SecretString) -> SectionResult {
-    let chars: Vec<char> = password.expose_secret().chars().collect();
-    if chars.len() < 3 {
-        return Ok(None);
-    }
-
-    // Check repeated chars (e.g., "aaa")
-    let mut repeated_count = 1;
-    for i in 1..chars.len() {
-        if chars[i] == chars[i - 1] {
-            repeated_count += 1;
-            if repeated_count >= 3 {
-                return Ok(Some("Password contains repetitive patterns".to_string()));
-            }
-        } else {
-            repeated_count = 1;
-        }
-    }
-
-    // Check for longer sequences (4+ consecutive characters)
-    for window_size in [4, 5] {
-        if chars.len() < window_size {
-            continue;
-        }
-
-        for i in window_size..=chars.len() {
-            let window = &chars[i - window_size..i];
-
-            // Check if all characters in window are sequential
-            let is_sequential = window.windows(2).all(|w| {
-                let prev = w[0] as i32;
-                let curr = w[1] as i32;
-                curr == prev + 1 || curr == prev - 1
-            });
-
-            if is_sequential {
-                return Ok(Some("Password contains sequential patterns".to_string()));
-            }
-        }
-    }
+pub fn pattern_analysis_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    Ok(pattern_section_code(password, ctx)?.map(pattern_reason))
+}
 
-    Ok(None)
+/// Like `pattern_analysis_section`, returning only the code.
+pub(crate) fn pattern_section_code(
+    password: &SecretString,
+    ctx: &SectionContext,
+) -> SectionCodeResult {
+    let rules = PatternRules {
+        repeat_threshold: ctx.config.repeat_threshold,
+        sequential_threshold: ctx.config.sequential_threshold,
+        keyboard_layout: ctx.config.keyboard_layout,
+    };
+    Ok(pattern_code_cancellable(
+        password.expose_secret(),
+        &rules,
+        || ctx.is_cancelled(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EvaluatorConfig;
+    use crate::keyboard::KeyboardLayout;
+    use crate::reason::ReasonCode;
+    use crate::sections::run_default;
 
     #[test]
     fn test_pattern_section_repetitive_chars() {
         let pwd = SecretString::new("aaaaBBBB1111".to_string().into());
-        let result = pattern_analysis_section(&pwd);
+        let result = run_default(pattern_analysis_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("repetitive"));
+            assert!(reason.message.contains("repetitive"));
         }
     }
 
     #[test]
     fn test_pattern_section_sequential_numbers() {
         let pwd = SecretString::new("test1234abcd".to_string().into());
-        let result = pattern_analysis_section(&pwd);
+        let result = run_default(pattern_analysis_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("sequential"));
+            assert!(reason.message.contains("sequential"));
         }
     }
 
     #[test]
     fn test_pattern_section_sequential_letters() {
         let pwd = SecretString::new("abcdTest123".to_string().into());
-        let result = pattern_analysis_section(&pwd);
+        let result = run_default(pattern_analysis_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("sequential"));
+            assert!(reason.message.contains("sequential"));
         }
     }
 
     #[test]
     fn test_pattern_section_strong_password() {
         let pwd = SecretString::new("RandomPass123!@#Word".to_string().into());
-        let result = pattern_analysis_section(&pwd);
+        let result = run_default(pattern_analysis_section, &pwd);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_pattern_section_returns_promptly_when_cancelled() {
+        use std::time::{Duration, Instant};
+        use tokio_util::sync::CancellationToken;
+
+        // No repeats or runs, so an uncancelled scan walks the whole input
+        let long: String = "aZ3!qW8@".repeat(2_000_000);
+        let pwd = SecretString::new(long.into());
+        let token = CancellationToken::new();
+
+        let canceller = {
+            let token = token.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(5));
+                token.cancel();
+            })
+        };
+        let start = Instant::now();
+        let result = pattern_analysis_section(
+            &pwd,
+            &SectionContext::new(&EvaluatorConfig::default()).with_token(Some(&token)),
+        );
+        let elapsed = start.elapsed();
+        canceller.join().unwrap();
+
         assert_eq!(result, Ok(None));
+        assert!(token.is_cancelled());
+        assert!(elapsed < Duration::from_secs(2), "took {:?}", elapsed);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_pattern_section_skips_when_already_cancelled() {
+        use tokio_util::sync::CancellationToken;
+
+        let pwd = SecretString::new("aaaa".to_string().into());
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = pattern_analysis_section(
+            &pwd,
+            &SectionContext::new(&EvaluatorConfig::default()).with_token(Some(&token)),
+        );
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_pattern_section_repeat_threshold() {
+        let pwd = SecretString::new("aabb".to_string().into());
+        assert_eq!(run_default(pattern_analysis_section, &pwd), Ok(None));
+
+        let config = EvaluatorConfig {
+            repeat_threshold: 2,
+            ..Default::default()
+        };
+        let result = pattern_analysis_section(&pwd, &SectionContext::new(&config));
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::Repetitive));
+    }
+
+    #[test]
+    fn test_pattern_section_sequential_threshold() {
+        let pwd = SecretString::new("abc7!X".to_string().into());
+        assert_eq!(run_default(pattern_analysis_section, &pwd), Ok(None));
+
+        let config = EvaluatorConfig {
+            sequential_threshold: 3,
+            ..Default::default()
+        };
+        let result = pattern_analysis_section(&pwd, &SectionContext::new(&config));
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::Sequential));
+    }
+
+    #[test]
+    fn test_pattern_section_keyboard_walk_layouts() {
+        let qwerty_walk = SecretString::new("Zasdf7!M".to_string().into());
+        let result = run_default(pattern_analysis_section, &qwerty_walk);
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::KeyboardWalk));
+
+        // Neighbours on AZERTY, but "q" sits on another row on QWERTY
+        let azerty_walk = SecretString::new("Xqsdf7!M".to_string().into());
+        assert_eq!(
+            run_default(pattern_analysis_section, &azerty_walk),
+            Ok(None)
+        );
+
+        let config = EvaluatorConfig {
+            keyboard_layout: KeyboardLayout::Azerty,
+            ..Default::default()
+        };
+        let result = pattern_analysis_section(&azerty_walk, &SectionContext::new(&config));
+        assert!(matches!(result, Ok(Some(ref r)) if r.code == ReasonCode::KeyboardWalk));
+    }
+
+    #[test]
+    fn test_pattern_section_phone_numbers_and_dates() {
+        let pwd = SecretString::new("Paul5551234567".to_string().into());
+        let result = run_default(pattern_analysis_section, &pwd);
+        assert!(
+            matches!(result, Ok(Some(ref r)) if r.message == "Password contains a phone number")
+        );
+
+        let pwd = SecretString::new("bob01011990".to_string().into());
+        let result = run_default(pattern_analysis_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.message == "Password contains a date"));
+    }
+
+    #[test]
+    fn test_pattern_section_palindromes() {
+        for palindrome in ["racecar", "ab11ba"] {
+            let pwd = SecretString::new(palindrome.to_string().into());
+            let result = run_default(pattern_analysis_section, &pwd);
+            assert!(
+                matches!(result, Ok(Some(ref r)) if r.message == "Password is a palindrome"),
+                "{} not flagged",
+                palindrome
+            );
+        }
+
+        let pwd = SecretString::new("racecars".to_string().into());
+        assert_eq!(run_default(pattern_analysis_section, &pwd), Ok(None));
     }
 
     #[test]
     fn test_pattern_section_too_short() {
         let pwd = SecretString::new("ab".to_string().into());
-        let result = pattern_analysis_section(&pwd);
+        let result = run_default(pattern_analysis_section, &pwd);
         assert_eq!(result, Ok(None));
     }
 }
@@ -1,84 +1,213 @@
 //! Character variety section - checks for uppercase, lowercase, numbers, special chars.
 
+use super::{SectionCodeResult, SectionContext, SectionResult};
+use crate::core::{check_variety, variety_code};
 use secrecy::{ExposeSecret, SecretString};
-use super::SectionResult;
 
-/// Checks if the password contains a variety of character types.
+/// Checks if the password contains each of the character types of
+/// `EvaluatorConfig::required_classes` (all four by default).
+///
+/// Scripts without case (Arabic, Hebrew, CJK, kana, ...) cannot satisfy
+/// the uppercase and lowercase requirements, so these are waived when the
+/// password has letters and none of them are cased. A password mixing
+/// cased and caseless letters (`"abc日本1!"`) still needs both cases.
+/// Only the characters of `EvaluatorConfig::special_chars` count as
+/// special characters.
+///
+/// The reason lists the missing classes in `Reason::missing_classes`.
+/// Passphrases (see `EvaluatorConfig::passphrase_mode`) have no required
+/// classes.
 ///
 /// # Returns
 /// - `Ok(Some(reason))` if missing required character types
-/// - `Ok(None)` if all character types are present
-pub fn character_variety_section(password: &SecretString) -> SectionResult {
+/// - `Ok(None)` if all required character types are present
+pub fn character_variety_section(password: &SecretString, ctx: &SectionContext) -> SectionResult {
+    let pwd = password.expose_secret();
+    if ctx.config.is_passphrase(pwd.chars().count()) {
+        return Ok(None);
+    }
+    Ok(check_variety(
+        pwd,
+        &ctx.config.required_classes,
+        &ctx.config.special_chars,
+    ))
+}
+
+/// Like `character_variety_section`, returning only the code.
+pub(crate) fn variety_section_code(
+    password: &SecretString,
+    ctx: &SectionContext,
+) -> SectionCodeResult {
     let pwd = password.expose_secret();
-    let has_upper = pwd.chars().any(|c| c.is_uppercase());
-    let has_lower = pwd.chars().any(|c| c.is_lowercase());
-    let has_digit = pwd.chars().any(|c| c.is_ascii_digit());
-    let has_special = pwd.chars().any(|c| !c.is_alphanumeric());
-
-    let missing: Vec<_> = vec![
-        if !has_upper { Some("uppercase") } else { None },
-        if !has_lower { Some("lowercase") } else { None },
-        if !has_digit { Some("numbers") } else { None },
-        if !has_special { Some("special characters") } else { None },
-    ]
-    .into_iter()
-    .flatten()
-    .collect();
-
-    if !missing.is_empty() {
-        return Ok(Some(format!("Missing: {}", missing.join(", "))));
+    if ctx.config.is_passphrase(pwd.chars().count()) {
+        return Ok(None);
     }
-    Ok(None)
+    Ok(variety_code(
+        pwd,
+        &ctx.config.required_classes,
+        &ctx.config.special_chars,
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::reason::CharClass;
+    use crate::sections::run_default;
 
     #[test]
     fn test_variety_section_missing_uppercase() {
         let pwd = SecretString::new("lowercase123!".to_string().into());
-        let result = character_variety_section(&pwd);
+        let result = run_default(character_variety_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("uppercase"));
+            assert!(reason.message.contains("uppercase"));
         }
     }
 
     #[test]
     fn test_variety_section_missing_lowercase() {
         let pwd = SecretString::new("UPPERCASE123!".to_string().into());
-        let result = character_variety_section(&pwd);
+        let result = run_default(character_variety_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("lowercase"));
+            assert!(reason.message.contains("lowercase"));
         }
     }
 
     #[test]
     fn test_variety_section_missing_numbers() {
         let pwd = SecretString::new("NoNumbers!".to_string().into());
-        let result = character_variety_section(&pwd);
+        let result = run_default(character_variety_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("numbers"));
+            assert!(reason.message.contains("numbers"));
         }
     }
 
     #[test]
     fn test_variety_section_missing_special() {
         let pwd = SecretString::new("NoSpecial123".to_string().into());
-        let result = character_variety_section(&pwd);
+        let result = run_default(character_variety_section, &pwd);
         assert!(matches!(result, Ok(Some(_))));
         if let Ok(Some(reason)) = result {
-            assert!(reason.contains("special"));
+            assert!(reason.message.contains("special"));
+        }
+    }
+
+    #[test]
+    fn test_variety_section_caseless_scripts_skip_case_requirements() {
+        // Japanese (kana) and Arabic letters have no case
+        let japanese = "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}2024!";
+        let arabic = "\u{0643}\u{0644}\u{0645}\u{0629}\u{0633}\u{0631}7#";
+        for password in [japanese, arabic] {
+            let pwd = SecretString::new(password.to_string().into());
+            assert_eq!(run_default(character_variety_section, &pwd), Ok(None));
         }
+
+        // Other requirements still apply
+        let pwd = SecretString::new(
+            "\u{30d1}\u{30b9}\u{30ef}\u{30fc}\u{30c9}!"
+                .to_string()
+                .into(),
+        );
+        let result = run_default(character_variety_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.message == "Missing: numbers"));
+    }
+
+    #[test]
+    fn test_variety_section_mixed_cased_and_caseless_letters() {
+        let pwd = SecretString::new("abc\u{65e5}\u{672c}1!".to_string().into());
+        let result = run_default(character_variety_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.message == "Missing: uppercase"));
+    }
+
+    #[test]
+    fn test_variety_section_missing_classes() {
+        let cases: [(&str, &[CharClass]); 4] = [
+            (
+                "lowercase",
+                &[CharClass::Uppercase, CharClass::Digit, CharClass::Special],
+            ),
+            ("UPPER123", &[CharClass::Lowercase, CharClass::Special]),
+            ("MixedCase!", &[CharClass::Digit]),
+            (
+                "12345678",
+                &[
+                    CharClass::Uppercase,
+                    CharClass::Lowercase,
+                    CharClass::Special,
+                ],
+            ),
+        ];
+        for (password, expected) in cases {
+            let pwd = SecretString::new(password.to_string().into());
+            let reason = run_default(character_variety_section, &pwd)
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                reason.code,
+                crate::reason::ReasonCode::MissingCharacterTypes
+            );
+            assert_eq!(reason.missing_classes, expected, "{password}");
+        }
+
+        let pwd = SecretString::new("UPPER123".to_string().into());
+        let reason = run_default(character_variety_section, &pwd)
+            .unwrap()
+            .unwrap();
+        assert_eq!(reason.to_string(), "Missing: lowercase, special characters");
+    }
+
+    #[test]
+    fn test_variety_section_uses_configured_special_chars() {
+        use crate::config::EvaluatorConfig;
+
+        // `~` is not in the default set
+        let pwd = SecretString::new("Tilde123~".to_string().into());
+        let result = run_default(character_variety_section, &pwd);
+        assert!(matches!(result, Ok(Some(ref r)) if r.missing_classes == [CharClass::Special]));
+
+        let config = EvaluatorConfig {
+            special_chars: "~".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            character_variety_section(&pwd, &SectionContext::new(&config)),
+            Ok(None)
+        );
+
+        // A restricted set no longer accepts the default symbols
+        let pwd = SecretString::new("Bang123!".to_string().into());
+        let result = character_variety_section(&pwd, &SectionContext::new(&config));
+        assert!(matches!(result, Ok(Some(ref r)) if r.missing_classes == [CharClass::Special]));
+    }
+
+    #[test]
+    fn test_variety_section_uses_configured_required_classes() {
+        use crate::config::EvaluatorConfig;
+
+        let config = EvaluatorConfig {
+            required_classes: vec![CharClass::Lowercase, CharClass::Digit],
+            ..Default::default()
+        };
+        let ctx = SectionContext::new(&config);
+
+        let pwd = SecretString::new("quietmoon42".to_string().into());
+        assert_eq!(character_variety_section(&pwd, &ctx), Ok(None));
+
+        let pwd = SecretString::new("QUIETMOON!".to_string().into());
+        let reason = character_variety_section(&pwd, &ctx).unwrap().unwrap();
+        assert_eq!(
+            reason.missing_classes,
+            [CharClass::Lowercase, CharClass::Digit]
+        );
     }
 
     #[test]
     fn test_variety_section_all_categories() {
         let pwd = SecretString::new("HasAll123!@#".to_string().into());
-        let result = character_variety_section(&pwd);
+        let result = run_default(character_variety_section, &pwd);
         assert_eq!(result, Ok(None));
     }
 }
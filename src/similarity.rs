@@ -0,0 +1,255 @@
+//! Similarity check - compares a new password against the previous one.
+
+use pwd_types::{PasswordEvaluation, PasswordScore};
+use secrecy::{ExposeSecret, SecretString};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::evaluate_password_strength_with_config;
+use crate::reason::ReasonCode;
+use crate::score::ScoreExt;
+
+/// Normalized similarity at or above which a password counts as a reuse.
+const MAX_SIMILARITY: f64 = 0.7;
+
+/// Evaluates a new password under the default configuration, penalizing
+/// it if it is too similar to `previous`.
+///
+/// The comparison is case-insensitive and uses the Levenshtein distance
+/// normalized by the longer length, so `"Summer2023!"` -> `"Summer2024!"`
+/// is flagged while an unrelated password is not. Empty passwords,
+/// passwords rejected for length and evaluations without a score are
+/// returned as is, without comparison.
+///
+/// # Arguments
+/// * `password` - The new password to evaluate
+/// * `previous` - The password being replaced
+///
+/// # Returns
+/// A `PasswordEvaluation` containing score and reasons.
+pub fn evaluate_against_previous(
+    password: &SecretString,
+    previous: &SecretString,
+) -> PasswordEvaluation {
+    evaluate_against_previous_with_config(password, previous, &EvaluatorConfig::default())
+}
+
+/// Like `evaluate_against_previous`, evaluating under `config`; the
+/// similarity penalty is `config.penalty(ReasonCode::TooSimilar)`.
+pub fn evaluate_against_previous_with_config(
+    password: &SecretString,
+    previous: &SecretString,
+    config: &EvaluatorConfig,
+) -> PasswordEvaluation {
+    let mut evaluation = evaluate_password_strength_with_config(
+        password,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    );
+
+    // Empty and oversized input is rejected before scoring; comparing it
+    // would add a reason to an evaluation that never assessed the password
+    if password.expose_secret().is_empty()
+        || password.expose_secret().len() > config.max_length
+        || previous.expose_secret().len() > config.max_length
+        || evaluation.score.is_none()
+    {
+        return evaluation;
+    }
+
+    if similarity(password.expose_secret(), previous.expose_secret()) >= MAX_SIMILARITY {
+        evaluation
+            .reasons
+            .push("Password is too similar to the previous password".to_string());
+        evaluation.score = evaluation.score.map(|s| {
+            PasswordScore::new_clamped(s.value() - config.penalty(ReasonCode::TooSimilar))
+        });
+    }
+    evaluation
+}
+
+/// Case-insensitive similarity in `[0.0, 1.0]`; `1.0` means identical.
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: Zeroizing<Vec<char>> = Zeroizing::new(a.chars().flat_map(char::to_lowercase).collect());
+    let b: Zeroizing<Vec<char>> = Zeroizing::new(b.chars().flat_map(char::to_lowercase).collect());
+
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f64 / longest as f64
+}
+
+/// Levenshtein distance using two rolling rows.
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let distance = prev[b.len()];
+    // The rows encode where the two passwords match
+    prev.zeroize();
+    curr.zeroize();
+    distance
+}
+
+/// Levenshtein distance if it is at most `max`, else `None`.
+///
+/// Gives up as soon as every cell of a row exceeds `max`, so far-apart
+/// strings cost less than a full `levenshtein`.
+pub(crate) fn bounded_levenshtein(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    let mut exceeded = false;
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+        if prev.iter().all(|&d| d > max) {
+            exceeded = true;
+            break;
+        }
+    }
+
+    let distance = prev[b.len()];
+    prev.zeroize();
+    curr.zeroize();
+    (!exceeded && distance <= max).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::{secret, setup_blacklist};
+    use serial_test::serial;
+
+    #[test]
+    fn test_levenshtein() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(levenshtein(&chars("kitten"), &chars("sitting")), 3);
+        assert_eq!(levenshtein(&chars(""), &chars("abc")), 3);
+        assert_eq!(levenshtein(&chars("abc"), &chars("abc")), 0);
+    }
+
+    #[test]
+    fn test_bounded_levenshtein() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(
+            bounded_levenshtein(&chars("kitten"), &chars("sitting"), 3),
+            Some(3)
+        );
+        assert_eq!(
+            bounded_levenshtein(&chars("kitten"), &chars("sitting"), 2),
+            None
+        );
+        assert_eq!(bounded_levenshtein(&chars(""), &chars("ab"), 2), Some(2));
+        assert_eq!(
+            bounded_levenshtein(&chars("abc"), &chars("xyzxyz"), 2),
+            None
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_one_char_edit_is_flagged() {
+        setup_blacklist();
+        let evaluation = evaluate_against_previous(&secret("Summer2024!"), &secret("Summer2023!"));
+        assert!(evaluation.reasons.iter().any(|r| r.contains("too similar")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_different_password_not_flagged() {
+        setup_blacklist();
+        let evaluation = evaluate_against_previous(&secret("Kx7#mQ2!vR9z"), &secret("Summer2023!"));
+        assert!(!evaluation.reasons.iter().any(|r| r.contains("too similar")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_similarity_penalty_applied() {
+        setup_blacklist();
+        let pwd = secret("Summer2024!");
+
+        #[cfg(feature = "async")]
+        let baseline = crate::evaluate_password_strength(&pwd, None);
+
+        #[cfg(not(feature = "async"))]
+        let baseline = crate::evaluate_password_strength(&pwd);
+
+        let evaluation = evaluate_against_previous(&pwd, &secret("summer2023!"));
+        assert_eq!(
+            evaluation.score.map(|s| s.value()),
+            baseline
+                .score
+                .map(|s| s.value() - EvaluatorConfig::default().penalty(ReasonCode::TooSimilar))
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_similarity_with_custom_config() {
+        setup_blacklist();
+        let config = EvaluatorConfig::default().with_penalty(ReasonCode::TooSimilar, 5);
+        let pwd = secret("Summer2024!");
+        let baseline = evaluate_password_strength_with_config(
+            &pwd,
+            &config,
+            #[cfg(feature = "async")]
+            None,
+        );
+
+        let evaluation =
+            evaluate_against_previous_with_config(&pwd, &secret("Summer2023!"), &config);
+        assert_eq!(
+            evaluation.score.map(|s| s.value()),
+            baseline.score.map(|s| s.value() - 5)
+        );
+
+        // The config's max_length applies to both passwords
+        let short_limit = EvaluatorConfig {
+            max_length: 10,
+            ..Default::default()
+        };
+        let evaluation = evaluate_against_previous_with_config(
+            &secret("Summer24!"),
+            &secret("Summer2023!"),
+            &short_limit,
+        );
+        assert!(!evaluation.reasons.iter().any(|r| r.contains("too similar")));
+    }
+
+    #[test]
+    #[serial]
+    fn test_empty_password_not_compared() {
+        setup_blacklist();
+        let evaluation = evaluate_against_previous(&secret(""), &secret(""));
+        assert_eq!(evaluation.reasons, ["Password is empty"]);
+        assert_eq!(evaluation.score.map(|s| s.value()), Some(0));
+    }
+
+    #[test]
+    #[serial]
+    fn test_similarity_penalty_never_below_zero() {
+        setup_blacklist();
+        let evaluation = evaluate_against_previous(&secret("qwerty"), &secret("qwerty1"));
+        assert!(evaluation.reasons.iter().any(|r| r.contains("too similar")));
+        assert_eq!(evaluation.score.map(|s| s.value()), Some(0));
+    }
+}
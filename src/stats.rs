@@ -0,0 +1,277 @@
+//! Character statistics - single-pass analysis of a password.
+
+use alloc::collections::BTreeSet;
+
+/// Character class of a single character, as counted by `CharStats`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Upper,
+    Lower,
+    Digit,
+    Special,
+    Other,
+}
+
+/// Class of each ASCII character, so the common case skips the Unicode
+/// property lookups.
+static ASCII_KINDS: [Kind; 128] = ascii_kinds();
+
+const fn ascii_kinds() -> [Kind; 128] {
+    let mut table = [Kind::Special; 128];
+    let mut i = 0;
+    while i < 128 {
+        let b = i as u8;
+        table[i] = if b.is_ascii_uppercase() {
+            Kind::Upper
+        } else if b.is_ascii_lowercase() {
+            Kind::Lower
+        } else if b.is_ascii_digit() {
+            Kind::Digit
+        } else {
+            Kind::Special
+        };
+        i += 1;
+    }
+    table
+}
+
+/// Classifies `c`, using `ASCII_KINDS` for ASCII characters.
+fn classify(c: char) -> Kind {
+    if c.is_ascii() {
+        ASCII_KINDS[c as usize]
+    } else {
+        classify_unicode(c)
+    }
+}
+
+/// Classifies `c` with the Unicode properties.
+fn classify_unicode(c: char) -> Kind {
+    if c.is_uppercase() {
+        Kind::Upper
+    } else if c.is_lowercase() {
+        Kind::Lower
+    } else if c.is_ascii_digit() {
+        Kind::Digit
+    } else if !c.is_alphanumeric() {
+        Kind::Special
+    } else {
+        Kind::Other
+    }
+}
+
+/// Number of characters in each class.
+///
+/// Every character is counted in exactly one class.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CharClassCounts {
+    /// Uppercase letters.
+    pub upper: usize,
+    /// Lowercase letters.
+    pub lower: usize,
+    /// ASCII digits.
+    pub digit: usize,
+    /// Special characters: in evaluations, those of
+    /// `EvaluatorConfig::special_chars` (the set given to
+    /// `CharStats::compute_with_specials`); whitespace and unlisted symbols
+    /// count as `other`. `CharStats::compute` counts every non-alphanumeric
+    /// character.
+    pub special: usize,
+    /// Alphanumeric characters without case that are not ASCII digits
+    /// (e.g. CJK ideographs, non-ASCII digits), and symbols outside the
+    /// special set given to `CharStats::compute_with_specials`.
+    pub other: usize,
+}
+
+impl CharClassCounts {
+    /// Number of classes among upper, lower, digit and special that are present.
+    pub fn variety(&self) -> usize {
+        [self.upper, self.lower, self.digit, self.special]
+            .iter()
+            .filter(|&&n| n > 0)
+            .count()
+    }
+}
+
+/// Statistics gathered in a single pass over the password.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CharStats {
+    /// Length in bytes.
+    pub byte_len: usize,
+    /// Length in characters.
+    pub char_len: usize,
+    /// Number of distinct characters.
+    pub unique: usize,
+    /// Characters per class.
+    pub counts: CharClassCounts,
+}
+
+impl CharStats {
+    /// Computes the statistics of `pwd`, counting every non-alphanumeric
+    /// character as special.
+    pub fn compute(pwd: &str) -> Self {
+        Self::compute_by(pwd, |_| true)
+    }
+
+    /// Computes the statistics of `pwd`, counting only the characters of
+    /// `specials` as special.
+    pub fn compute_with_specials(pwd: &str, specials: &str) -> Self {
+        Self::compute_by(pwd, |c| specials.contains(c))
+    }
+
+    fn compute_by(pwd: &str, is_special: impl Fn(char) -> bool) -> Self {
+        let mut counts = CharClassCounts::default();
+        let mut ascii_seen = [false; 128];
+        let mut seen = BTreeSet::new();
+        let mut char_len = 0;
+
+        for c in pwd.chars() {
+            char_len += 1;
+            if c.is_ascii() {
+                ascii_seen[c as usize] = true;
+            } else {
+                seen.insert(c);
+            }
+            match classify(c) {
+                Kind::Upper => counts.upper += 1,
+                Kind::Lower => counts.lower += 1,
+                Kind::Digit => counts.digit += 1,
+                Kind::Special if is_special(c) => counts.special += 1,
+                Kind::Special => counts.other += 1,
+                Kind::Other => counts.other += 1,
+            }
+        }
+
+        Self {
+            byte_len: pwd.len(),
+            char_len,
+            unique: seen.len() + ascii_seen.iter().filter(|&&s| s).count(),
+            counts,
+        }
+    }
+
+    /// Estimated entropy in bits, assuming each character is drawn
+    /// uniformly from the union of the classes present.
+    ///
+    /// This is an upper bound: it ignores dictionary words and patterns,
+    /// which the evaluation sections penalize separately. Requires the
+    /// `std` feature, which provides the logarithm.
+    #[cfg(feature = "std")]
+    pub fn entropy_bits(&self) -> f64 {
+        // Assumed alphabet size for each character class
+        const UPPER_POOL: usize = 26;
+        const LOWER_POOL: usize = 26;
+        const DIGIT_POOL: usize = 10;
+        const SPECIAL_POOL: usize = 33;
+        const OTHER_POOL: usize = 100;
+
+        let c = &self.counts;
+        let pool: usize = [
+            (c.upper, UPPER_POOL),
+            (c.lower, LOWER_POOL),
+            (c.digit, DIGIT_POOL),
+            (c.special, SPECIAL_POOL),
+            (c.other, OTHER_POOL),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(_, size)| size)
+        .sum();
+        if pool == 0 {
+            return 0.0;
+        }
+        self.char_len as f64 * (pool as f64).log2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_stats_known_password() {
+        let stats = CharStats::compute("Ab12!!cd");
+        assert_eq!(
+            stats.counts,
+            CharClassCounts {
+                upper: 1,
+                lower: 3,
+                digit: 2,
+                special: 2,
+                other: 0
+            }
+        );
+        assert_eq!(stats.char_len, 8);
+        assert_eq!(stats.byte_len, 8);
+        assert_eq!(stats.unique, 7);
+        assert_eq!(stats.counts.variety(), 4);
+    }
+
+    #[test]
+    fn test_char_stats_non_ascii() {
+        let stats = CharStats::compute("\u{00c9}t\u{00e9}\u{6f22}\u{0663} ");
+        assert_eq!(
+            stats.counts,
+            CharClassCounts {
+                upper: 1,
+                lower: 2,
+                digit: 0,
+                special: 1,
+                other: 2
+            }
+        );
+        assert_eq!(stats.char_len, 6);
+        assert!(stats.byte_len > stats.char_len);
+    }
+
+    #[test]
+    fn test_char_stats_empty() {
+        assert_eq!(CharStats::compute(""), CharStats::default());
+    }
+
+    #[test]
+    fn test_ascii_table_matches_unicode_classification() {
+        for b in 0u8..128 {
+            let c = b as char;
+            assert_eq!(classify(c), classify_unicode(c), "{:?}", c);
+        }
+        let stats = CharStats::compute("Tr0ub4dor&3 ~x\t");
+        assert_eq!(
+            stats.counts,
+            CharClassCounts {
+                upper: 1,
+                lower: 7,
+                digit: 3,
+                special: 4,
+                other: 0
+            }
+        );
+        assert_eq!(stats.unique, 14);
+    }
+
+    #[test]
+    fn test_char_stats_with_specials() {
+        let stats = CharStats::compute_with_specials("ab~ ~!", "!");
+        assert_eq!(
+            stats.counts,
+            CharClassCounts {
+                upper: 0,
+                lower: 2,
+                digit: 0,
+                special: 1,
+                other: 3
+            }
+        );
+        assert_eq!(stats.unique, 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_entropy_bits() {
+        assert_eq!(CharStats::default().entropy_bits(), 0.0);
+        // 8 lowercase letters: 8 * log2(26)
+        let bits = CharStats::compute("abcdefgh").entropy_bits();
+        assert!((bits - 8.0 * 26f64.log2()).abs() < 1e-9);
+        // Adding classes widens the pool
+        assert!(CharStats::compute("aB3!efgh").entropy_bits() > bits);
+    }
+}
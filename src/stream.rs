@@ -0,0 +1,317 @@
+//! Streaming evaluation - yields progress as each section completes.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pwd_types::PasswordEvaluation;
+use secrecy::{ExposeSecret, SecretString};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::config::EvaluatorConfig;
+use crate::evaluator::{SECTIONS, cancelled, finish, reject_invalid};
+use crate::reason::{Reason, ReasonCode};
+use crate::sections::SectionContext;
+use crate::stats::CharStats;
+
+/// Outcome of a single evaluation section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionProgress {
+    /// Section name (e.g. `"length"`).
+    pub name: &'static str,
+    /// Whether the section passed.
+    pub passed: bool,
+    /// Reason reported by the section, if it failed.
+    pub reason: Option<String>,
+}
+
+/// Event emitted during an incremental evaluation.
+#[derive(Debug)]
+pub enum EvalEvent {
+    /// A section has completed.
+    Progress(SectionProgress),
+    /// The evaluation has finished (or was cancelled). Always the last event.
+    Complete(PasswordEvaluation),
+}
+
+/// Evaluates password strength, yielding an event after each section.
+///
+/// Each poll runs at most one section, so a UI can update incrementally.
+/// The stream ends with a single `EvalEvent::Complete` carrying the same
+/// evaluation `evaluate_password_strength` would return. Cancellation is
+/// checked before each section; a cancelled stream completes with the same
+/// clean result as a cancelled `evaluate_password_strength`, discarding
+/// reasons reported by earlier `Progress` events.
+///
+/// # Arguments
+/// * `password` - The password to evaluate
+/// * `token` - Optional cancellation token
+pub fn evaluate_password_strength_stream(
+    password: &SecretString,
+    token: Option<CancellationToken>,
+) -> impl Stream<Item = EvalEvent> + Unpin {
+    let config = EvaluatorConfig::default();
+    EvaluationStream {
+        rejected: reject_invalid(password, &config),
+        config,
+        password,
+        #[cfg(feature = "unicode-normalization")]
+        normalized: crate::normalize::normalize_secret(password),
+        token,
+        next: 0,
+        reasons: Vec::new(),
+        done: false,
+    }
+}
+
+/// Channel version of `evaluate_password_strength_stream`.
+///
+/// Waits `DEFAULT_DEBOUNCE` like `evaluate_password_strength_tx`, then
+/// sends an `EvalEvent::Progress` as each section completes and a final
+/// `EvalEvent::Complete`, so a UI gets feedback during slow checks.
+/// Stops early if the receiver is dropped.
+pub async fn evaluate_password_strength_tx_events(
+    password: &SecretString,
+    token: CancellationToken,
+    tx: mpsc::Sender<EvalEvent>,
+) {
+    tokio::time::sleep(crate::live::DEFAULT_DEBOUNCE).await;
+
+    let mut stream = evaluate_password_strength_stream(password, Some(token));
+    while let Some(event) = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+        if let Err(_e) = tx.send(event).await {
+            #[cfg(feature = "tracing")]
+            tracing::error!("Failed to send password evaluation event: {}", _e);
+            return;
+        }
+    }
+}
+
+struct EvaluationStream<'a> {
+    password: &'a SecretString,
+    config: EvaluatorConfig,
+    #[cfg(feature = "unicode-normalization")]
+    normalized: Option<SecretString>,
+    token: Option<CancellationToken>,
+    /// Index of the next section to run.
+    next: usize,
+    reasons: Vec<Reason>,
+    /// Evaluation returned without running any section (oversized input).
+    rejected: Option<PasswordEvaluation>,
+    done: bool,
+}
+
+impl Stream for EvaluationStream<'_> {
+    type Item = EvalEvent;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<EvalEvent>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        if let Some(rejected) = this.rejected.take() {
+            this.done = true;
+            return Poll::Ready(Some(EvalEvent::Complete(rejected)));
+        }
+
+        if this.token.as_ref().is_some_and(|t| t.is_cancelled()) {
+            this.done = true;
+            this.reasons.clear();
+            return Poll::Ready(Some(EvalEvent::Complete(cancelled())));
+        }
+
+        #[cfg(feature = "unicode-normalization")]
+        let password = this.normalized.as_ref().unwrap_or(this.password);
+        #[cfg(not(feature = "unicode-normalization"))]
+        let password = this.password;
+
+        let Some(&(name, section_fn)) = SECTIONS.get(this.next) else {
+            this.done = true;
+            let reasons = std::mem::take(&mut this.reasons);
+            let stats = CharStats::compute_with_specials(
+                password.expose_secret(),
+                &this.config.special_chars,
+            );
+            return Poll::Ready(Some(EvalEvent::Complete(finish(
+                &stats,
+                reasons,
+                &this.config,
+            ))));
+        };
+        this.next += 1;
+
+        let ctx = SectionContext::new(&this.config).with_token(this.token.as_ref());
+        let progress = match section_fn(password, &ctx) {
+            Ok(Some(reason)) => {
+                let message = reason.message.clone();
+                this.reasons.push(reason);
+                SectionProgress {
+                    name,
+                    passed: false,
+                    reason: Some(message),
+                }
+            }
+            Ok(None) => SectionProgress {
+                name,
+                passed: true,
+                reason: None,
+            },
+            Err(()) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!("Fatal error in password evaluation section: {}", name);
+                this.reasons.push(Reason::new(ReasonCode::Error, "Error"));
+                // Skip the remaining sections, as the synchronous evaluator does
+                this.next = SECTIONS.len();
+                SectionProgress {
+                    name,
+                    passed: false,
+                    reason: Some("Error".to_string()),
+                }
+            }
+        };
+        Poll::Ready(Some(EvalEvent::Progress(progress)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (1, Some(SECTIONS.len() - self.next + 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::evaluate_password_strength;
+    use crate::sections::setup_blacklist;
+    use serial_test::serial;
+    use std::future::poll_fn;
+
+    async fn collect(password: &SecretString, token: Option<CancellationToken>) -> Vec<EvalEvent> {
+        let mut stream = evaluate_password_strength_stream(password, token);
+        let mut events = Vec::new();
+        while let Some(event) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            events.push(event);
+        }
+        events
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_stream_yields_progress_per_section_then_complete() {
+        setup_blacklist();
+        let pwd = SecretString::new("abc".to_string().into());
+
+        let events = collect(&pwd, None).await;
+
+        assert_eq!(events.len(), SECTIONS.len() + 1);
+        let names: Vec<&str> = events[..SECTIONS.len()]
+            .iter()
+            .map(|e| match e {
+                EvalEvent::Progress(p) => p.name,
+                EvalEvent::Complete(_) => panic!("Complete before all sections ran"),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            ["blacklist", "denylist", "length", "variety", "pattern"]
+        );
+
+        let EvalEvent::Complete(evaluation) = &events[SECTIONS.len()] else {
+            panic!("Last event should be Complete");
+        };
+        let expected = evaluate_password_strength(&pwd, None);
+        assert_eq!(
+            evaluation.score.as_ref().map(|s| s.value()),
+            expected.score.map(|s| s.value())
+        );
+        assert_eq!(evaluation.reasons, expected.reasons);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_stream_progress_reports_failures() {
+        setup_blacklist();
+        let pwd = SecretString::new("password".to_string().into());
+
+        let events = collect(&pwd, None).await;
+
+        let EvalEvent::Progress(blacklist) = &events[0] else {
+            panic!("First event should be Progress");
+        };
+        assert_eq!(blacklist.name, "blacklist");
+        assert!(!blacklist.passed);
+        assert!(blacklist.reason.is_some());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_tx_events_sends_progress_before_complete() {
+        setup_blacklist();
+        let pwd = SecretString::new("TestPass123!".to_string().into());
+        let (tx, mut rx) = mpsc::channel(1);
+
+        let receive = async {
+            let mut events = Vec::new();
+            while let Some(event) = rx.recv().await {
+                events.push(event);
+            }
+            events
+        };
+        let ((), events) = tokio::join!(
+            evaluate_password_strength_tx_events(&pwd, CancellationToken::new(), tx),
+            receive
+        );
+
+        let (last, progress) = events.split_last().expect("Should receive events");
+        assert!(!progress.is_empty());
+        assert!(progress.iter().all(|e| matches!(e, EvalEvent::Progress(_))));
+        let EvalEvent::Complete(evaluation) = last else {
+            panic!("Last event should be Complete");
+        };
+        assert!(evaluation.score.is_some());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_stream_cancelled() {
+        setup_blacklist();
+        let token = CancellationToken::new();
+        token.cancel();
+        let pwd = SecretString::new("SomePassword123!".to_string().into());
+
+        let events = collect(&pwd, Some(token)).await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], EvalEvent::Complete(e) if e.score.is_none()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_stream_cancelled_after_failed_section_is_clean() {
+        setup_blacklist();
+        let token = CancellationToken::new();
+        let pwd = SecretString::new("password".to_string().into());
+        let mut stream = evaluate_password_strength_stream(&pwd, Some(token.clone()));
+
+        let first = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
+        assert!(matches!(first, Some(EvalEvent::Progress(ref p)) if !p.passed));
+
+        token.cancel();
+        let Some(EvalEvent::Complete(evaluation)) =
+            poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await
+        else {
+            panic!("Cancelled stream should complete");
+        };
+        assert!(evaluation.score.is_none());
+        assert_eq!(
+            evaluation.strength(),
+            pwd_types::PasswordStrength::NotEvaluated
+        );
+        assert_eq!(evaluation.reasons, vec!["Evaluation cancelled".to_string()]);
+    }
+}
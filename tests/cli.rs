@@ -0,0 +1,61 @@
+//! Integration tests for the `pwd-strength` binary.
+
+#![cfg(feature = "cli")]
+
+use assert_cmd::Command;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+fn blacklist_file() -> NamedTempFile {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    for pwd in ["password", "123456", "qwerty"] {
+        writeln!(temp_file, "{}", pwd).expect("Failed to write");
+    }
+    temp_file
+}
+
+fn cmd(blacklist: &NamedTempFile) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_pwd-strength"));
+    cmd.env("PWD_BLACKLIST_PATH", blacklist.path());
+    cmd
+}
+
+#[test]
+fn test_cli_strong_password_from_stdin() {
+    let blacklist = blacklist_file();
+    let assert = cmd(&blacklist)
+        .write_stdin("VeryStrongPassword123!@#\n")
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(stdout.contains("Score:"));
+    assert!(stdout.contains("Strength:"));
+}
+
+#[test]
+fn test_cli_weak_password_exits_non_zero() {
+    let blacklist = blacklist_file();
+    let assert = cmd(&blacklist).write_stdin("password\n").assert().code(1);
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(stdout.contains("WEAK"));
+    assert!(stdout.contains("common"));
+}
+
+#[test]
+fn test_cli_password_argument_json() {
+    let blacklist = blacklist_file();
+    let assert = cmd(&blacklist).args(["--json", "abc"]).assert().code(1);
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(stdout.starts_with("{\"score\":"));
+    assert!(stdout.contains("\"strength\":\"WEAK\""));
+    assert!(stdout.contains("\"reasons\":[\""));
+}
+
+#[test]
+fn test_cli_unknown_option() {
+    let blacklist = blacklist_file();
+    cmd(&blacklist).arg("--bogus").assert().code(2);
+}
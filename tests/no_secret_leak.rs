@@ -0,0 +1,167 @@
+//! Guarantees that evaluation output never contains the password.
+//!
+//! Every reason, result and report produced while evaluating a
+//! distinctive password is rendered with `Display` and `Debug` and checked
+//! for the plaintext. With the `tracing` feature, the fields of every span
+//! are checked as well.
+
+#![cfg(feature = "std")]
+
+use pwd_strength::{
+    EvaluatorConfig, HashAlgorithm, PasswordHash, Section, evaluate_against_previous,
+    evaluate_bytes, evaluate_password_strength_detailed, evaluate_report, evaluate_str,
+    evaluate_with_history, init_blacklist_from_iter, init_denylist_from_iter,
+    init_dictionary_from_iter,
+};
+use secrecy::SecretString;
+
+/// Passwords that trigger the blacklist, denylist, dictionary and pattern
+/// sections. Distinctive enough that no fixed message contains them.
+const SECRETS: [&str; 5] = [
+    "zanzibarquokka",
+    "zanzibarquokka7!",
+    "xqvenomdrift",
+    "Quokkazygote1",
+    "zzzzqwertyzzzz",
+];
+
+fn secret(password: &str) -> SecretString {
+    SecretString::new(password.to_string().into())
+}
+
+fn assert_no_leak(password: &str, output: &str) {
+    let lower = output.to_lowercase();
+    assert!(
+        !lower.contains(&password.to_lowercase()),
+        "password leaked into output: {output}"
+    );
+}
+
+/// Renders everything the public API returns for `password`.
+fn outputs(password: &str, config: &EvaluatorConfig) -> Vec<String> {
+    let pwd = secret(password);
+    let mut outputs = Vec::new();
+
+    let detailed = evaluate_password_strength_detailed(
+        &pwd,
+        config,
+        #[cfg(feature = "async")]
+        None,
+    );
+    outputs.extend(detailed.evaluation.reasons.iter().cloned());
+    outputs.push(format!("{:?}", detailed));
+
+    outputs.push(format!("{:?}", evaluate_report(&pwd, config)));
+    outputs.push(format!("{:?}", evaluate_str(password)));
+    outputs.push(format!("{:?}", evaluate_bytes(password.as_bytes())));
+    outputs.push(format!(
+        "{:?}",
+        evaluate_against_previous(&pwd, &secret(password))
+    ));
+
+    let history = [PasswordHash::compute(HashAlgorithm::Sha256, &pwd)];
+    outputs.push(format!("{:?}", evaluate_with_history(&pwd, &history)));
+    outputs.push(format!("{:?}", history));
+    outputs.push(format!("{:?}", pwd));
+
+    outputs
+}
+
+#[test]
+fn test_no_output_contains_the_password() {
+    init_blacklist_from_iter(["zanzibarquokka", "password"]).unwrap();
+    init_denylist_from_iter(["venom"]).unwrap();
+    init_dictionary_from_iter(["quokka"]).unwrap();
+
+    let mut sections = Section::DEFAULT.to_vec();
+    sections.push(Section::Dictionary);
+    let configs = [
+        EvaluatorConfig {
+            sections,
+            blacklist_strip_affixes: true,
+            ..Default::default()
+        },
+        EvaluatorConfig {
+            blacklist_substring_min_length: Some(6),
+            min_score: Some(90),
+            ..Default::default()
+        },
+        EvaluatorConfig {
+            fail_fast: true,
+            ..Default::default()
+        },
+        EvaluatorConfig {
+            max_length: 8,
+            ..Default::default()
+        },
+    ];
+
+    for password in SECRETS {
+        for config in &configs {
+            for output in outputs(password, config) {
+                assert_no_leak(password, &output);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_no_span_field_contains_the_password() {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+
+    struct Values<'a>(&'a mut Vec<String>);
+
+    impl Visit for Values<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+
+    struct Capture {
+        next_id: AtomicU64,
+        values: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl tracing::Subscriber for Capture {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut Values(&mut self.values.lock().unwrap()));
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut Values(&mut self.values.lock().unwrap()));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &tracing::Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let values = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = Capture {
+        next_id: AtomicU64::new(0),
+        values: Arc::clone(&values),
+    };
+    tracing::subscriber::with_default(subscriber, || {
+        for password in SECRETS {
+            evaluate_str(password);
+        }
+    });
+
+    let values = values.lock().unwrap();
+    assert!(!values.is_empty());
+    for password in SECRETS {
+        for value in values.iter() {
+            assert_no_leak(password, value);
+        }
+    }
+}